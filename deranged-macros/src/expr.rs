@@ -0,0 +1,217 @@
+//! Ranged integers with an explicit primitive type, whose bounds may be const expressions.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use crate::helpers::{compile_error, Separator};
+use crate::integer::{Integer, Primitive, Suffix};
+use crate::ty::Type;
+
+/// Whether the input is of the form `primitive; min, max`.
+pub(crate) fn is_typed(input: &TokenStream) -> bool {
+    matches!(input.clone().into_iter().next(), Some(TokenTree::Ident(_)))
+}
+
+/// Split the bounds at the first top-level comma or range operator.
+fn split_bounds(
+    tokens: Vec<TokenTree>,
+) -> Result<(Vec<TokenTree>, Separator, Vec<TokenTree>), TokenStream> {
+    let is_punct = |index: usize, ch: char| match tokens.get(index) {
+        Some(TokenTree::Punct(punct)) => punct.as_char() == ch,
+        _ => false,
+    };
+
+    for (index, token) in tokens.iter().enumerate() {
+        let TokenTree::Punct(punct) = token else {
+            continue;
+        };
+        let (separator, len) = match punct.as_char() {
+            ',' => (Separator::Comma, 1),
+            '.' if punct.spacing() == Spacing::Joint && is_punct(index + 1, '.') => {
+                if is_punct(index + 2, '=') {
+                    (Separator::RangeInclusive, 3)
+                } else {
+                    (Separator::RangeExclusive, 2)
+                }
+            }
+            _ => continue,
+        };
+
+        let mut min = tokens;
+        let max = min.split_off(index + len);
+        min.truncate(index);
+        return Ok((min, separator, max));
+    }
+
+    Err(compile_error(
+        "minimum and maximum value must be separated by a comma or range operator",
+        None,
+    ))
+}
+
+/// Wrap an expression in braces so that it can be used as a const generic argument. Literals and
+/// blocks are left as-is, as braces around them would be linted as unnecessary.
+fn const_arg(mut tokens: Vec<TokenTree>) -> TokenTree {
+    let is_unwrapped = match tokens.as_slice() {
+        [TokenTree::Literal(_)] => true,
+        [TokenTree::Group(group)] => group.delimiter() == Delimiter::Brace,
+        _ => false,
+    };
+    if is_unwrapped {
+        if let Some(token) = tokens.pop() {
+            return token;
+        }
+    }
+    TokenTree::Group(Group::new(Delimiter::Brace, TokenStream::from_iter(tokens)))
+}
+
+/// Parse the tokens as an integer literal, returning `None` if they are any other expression.
+fn parse_literal(tokens: &[TokenTree], what: &str) -> Option<Integer> {
+    let mut iter = TokenStream::from_iter(tokens.iter().cloned()).into_iter();
+    let integer = Integer::try_from_tokens(&mut iter, what).ok()?;
+    iter.next().is_none().then_some(integer)
+}
+
+/// The bounds of a ranged integer with an explicit primitive type.
+pub(crate) enum Bounds {
+    /// Both bounds are integer literals, which have been checked against the primitive type. The
+    /// maximum value is inclusive.
+    Literal(Integer, Integer),
+    /// At least one bound is a const expression. The maximum value is inclusive.
+    Expr {
+        /// The primitive type of the ranged integer.
+        primitive: Primitive,
+        /// The minimum value.
+        min: Vec<TokenTree>,
+        /// The maximum value.
+        max: Vec<TokenTree>,
+    },
+}
+
+/// Parse `primitive; min, max` (or a range in place of `min, max`).
+///
+/// Bounds that are integer literals are checked against the primitive type as with [`Type`], and
+/// their suffixes must agree with it. Any other bounds are not checked by the macro; the ranged
+/// type checks them when it is used.
+pub(crate) fn parse_bounds(input: TokenStream) -> Result<Bounds, TokenStream> {
+    let mut iter = input.into_iter();
+
+    let Some(TokenTree::Ident(primitive_ident)) = iter.next() else {
+        return Err(compile_error("expected primitive integer type", None));
+    };
+    let Some(primitive) = Primitive::from_name(&primitive_ident.to_string()) else {
+        return Err(compile_error(
+            "expected primitive integer type",
+            primitive_ident.span(),
+        ));
+    };
+
+    match iter.next() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => {}
+        Some(token) => return Err(compile_error("expected `;`", token.span())),
+        None => return Err(compile_error("expected `;`", primitive_ident.span())),
+    }
+
+    let (mut min, separator, mut max) = split_bounds(iter.collect())?;
+
+    if min.is_empty() {
+        // A range without a lower bound starts at zero.
+        if separator == Separator::Comma {
+            return Err(compile_error("expected minimum value", None));
+        }
+        min.push(TokenTree::Literal(Literal::u8_unsuffixed(0)));
+    }
+    if max.is_empty() {
+        return Err(compile_error("expected maximum value", None));
+    }
+
+    if let (Some(mut min), Some(mut max)) = (
+        parse_literal(&min, "minimum value"),
+        parse_literal(&max, "maximum value"),
+    ) {
+        // A suffix may only restate the explicit type, either exactly or by its signedness.
+        for integer in [&mut min, &mut max] {
+            integer.suffix = match integer.suffix {
+                Suffix::Either => Suffix::Exact(primitive),
+                Suffix::Unsigned if !primitive.is_signed() => Suffix::Exact(primitive),
+                Suffix::Signed if primitive.is_signed() => Suffix::Exact(primitive),
+                Suffix::Exact(suffix) if suffix == primitive => Suffix::Exact(primitive),
+                _ => {
+                    return Err(compile_error(
+                        &format!(
+                            "suffix conflicts with the explicit type `{}`",
+                            primitive.name()
+                        ),
+                        integer.span,
+                    ));
+                }
+            };
+        }
+        if separator == Separator::RangeExclusive {
+            max = max.pred();
+        }
+        // Check the bounds against the primitive type.
+        Type::<false>::from_min_max(&min, &max)?;
+        return Ok(Bounds::Literal(min, max));
+    }
+
+    if separator == Separator::RangeExclusive {
+        max = vec![
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                TokenStream::from_iter(max),
+            )),
+            TokenTree::Punct(Punct::new('-', Spacing::Alone)),
+            TokenTree::Literal(Literal::u8_unsuffixed(1)),
+        ];
+    }
+
+    Ok(Bounds::Expr {
+        primitive,
+        min,
+        max,
+    })
+}
+
+impl Bounds {
+    /// Convert the bounds to the ranged type.
+    pub(crate) fn into_tokens<const OPTIONAL: bool>(self) -> Result<TokenStream, TokenStream> {
+        let (primitive, min, max) = match self {
+            Self::Literal(min, max) => {
+                return Ok(Type::<OPTIONAL>::from_min_max(&min, &max)?.into_tokens());
+            }
+            Self::Expr {
+                primitive,
+                min,
+                max,
+            } => (primitive, min, max),
+        };
+
+        let type_name = if OPTIONAL {
+            format!("Option{}", primitive.ranged_type_name())
+        } else {
+            primitive.ranged_type_name().to_owned()
+        };
+
+        Ok(TokenStream::from_iter([
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("deranged", Span::mixed_site())),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new(&type_name, Span::call_site())),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+            const_arg(min),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+            const_arg(max),
+            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+        ]))
+    }
+}
+
+/// Expand `primitive; min, max` (or a range in place of `min, max`) to the ranged type with the
+/// provided bounds.
+pub(crate) fn expand<const OPTIONAL: bool>(input: TokenStream) -> Result<TokenStream, TokenStream> {
+    parse_bounds(input)?.into_tokens::<OPTIONAL>()
+}
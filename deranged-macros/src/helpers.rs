@@ -1,6 +1,8 @@
 //! Helpers to make writing macros easier.
 
-use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use proc_macro::{
+    token_stream, Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree,
+};
 
 /// Unwrap a `Result` or return the error directly.
 macro_rules! unwrap_or_return {
@@ -68,25 +70,52 @@ pub(crate) fn compile_error(message: &str, span: impl MaybeSpan) -> TokenStream
     ])
 }
 
-/// Consume a comma, returning a `TokenStream` describing the error upon failure.
-pub(crate) fn parse_comma(iter: &mut impl Iterator<Item = TokenTree>) -> Result<(), TokenStream> {
+/// The separator between the minimum and maximum values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Separator {
+    /// `,`, with both bounds inclusive.
+    Comma,
+    /// `..=`, with both bounds inclusive.
+    RangeInclusive,
+    /// `..`, with the maximum value exclusive.
+    RangeExclusive,
+}
+
+/// Consume a comma, `..`, or `..=`, returning a `TokenStream` describing the error upon failure.
+pub(crate) fn parse_separator(iter: &mut token_stream::IntoIter) -> Result<Separator, TokenStream> {
+    const MESSAGE: &str =
+        "minimum and maximum value must be separated by a comma or range operator";
+
     match iter.next() {
-        Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => Ok(()),
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => Ok(Separator::Comma),
+        Some(TokenTree::Punct(first))
+            if first.as_char() == '.' && first.spacing() == Spacing::Joint =>
+        {
+            match iter.next() {
+                Some(TokenTree::Punct(second)) if second.as_char() == '.' => {
+                    // The second `.` is joint to whatever follows it, such as the `-` of a
+                    // negative maximum value, so only a following `=` makes the range inclusive.
+                    match iter.clone().next() {
+                        Some(TokenTree::Punct(third)) if third.as_char() == '=' => {
+                            iter.next();
+                            Ok(Separator::RangeInclusive)
+                        }
+                        _ => Ok(Separator::RangeExclusive),
+                    }
+                }
+                Some(token) => Err(compile_error(MESSAGE, (first.span(), token.span()))),
+                None => Err(compile_error(MESSAGE, first.span())),
+            }
+        }
         Some(TokenTree::Punct(punct)) => {
             let first_span = punct.span();
             let last_span = iter
                 .take_while(|token| matches!(token, TokenTree::Punct(_)))
                 .last()
                 .map_or(first_span, |token| token.span());
-            Err(compile_error(
-                "minimum and maximum value must be separated by a comma",
-                (first_span, last_span),
-            ))
+            Err(compile_error(MESSAGE, (first_span, last_span)))
         }
-        Some(token) => Err(compile_error(
-            "minimum and maximum value must be separated by a comma",
-            token.span(),
-        )),
+        Some(token) => Err(compile_error(MESSAGE, token.span())),
         None => Err(compile_error("expected maximum value", None)),
     }
 }
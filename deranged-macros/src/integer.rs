@@ -4,13 +4,109 @@ use proc_macro::{token_stream, Span, TokenStream, TokenTree};
 
 use crate::helpers::compile_error;
 
+/// A primitive integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Primitive {
+    #[allow(clippy::missing_docs_in_private_items)]
+    U8,
+    #[allow(clippy::missing_docs_in_private_items)]
+    U16,
+    #[allow(clippy::missing_docs_in_private_items)]
+    U32,
+    #[allow(clippy::missing_docs_in_private_items)]
+    U64,
+    #[allow(clippy::missing_docs_in_private_items)]
+    U128,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Usize,
+    #[allow(clippy::missing_docs_in_private_items)]
+    I8,
+    #[allow(clippy::missing_docs_in_private_items)]
+    I16,
+    #[allow(clippy::missing_docs_in_private_items)]
+    I32,
+    #[allow(clippy::missing_docs_in_private_items)]
+    I64,
+    #[allow(clippy::missing_docs_in_private_items)]
+    I128,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Isize,
+}
+
+impl Primitive {
+    /// Obtain the primitive integer type with the provided name.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "u8" => Self::U8,
+            "u16" => Self::U16,
+            "u32" => Self::U32,
+            "u64" => Self::U64,
+            "u128" => Self::U128,
+            "usize" => Self::Usize,
+            "i8" => Self::I8,
+            "i16" => Self::I16,
+            "i32" => Self::I32,
+            "i64" => Self::I64,
+            "i128" => Self::I128,
+            "isize" => Self::Isize,
+            _ => return None,
+        })
+    }
+
+    /// The name of the primitive integer type.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::U128 => "u128",
+            Self::Usize => "usize",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::I128 => "i128",
+            Self::Isize => "isize",
+        }
+    }
+
+    /// The name of the ranged integer type whose inner type is this primitive.
+    pub(crate) const fn ranged_type_name(self) -> &'static str {
+        match self {
+            Self::U8 => "RangedU8",
+            Self::U16 => "RangedU16",
+            Self::U32 => "RangedU32",
+            Self::U64 => "RangedU64",
+            Self::U128 => "RangedU128",
+            Self::Usize => "RangedUsize",
+            Self::I8 => "RangedI8",
+            Self::I16 => "RangedI16",
+            Self::I32 => "RangedI32",
+            Self::I64 => "RangedI64",
+            Self::I128 => "RangedI128",
+            Self::Isize => "RangedIsize",
+        }
+    }
+
+    /// Whether the primitive integer type is signed.
+    pub(crate) const fn is_signed(self) -> bool {
+        matches!(
+            self,
+            Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128 | Self::Isize
+        )
+    }
+}
+
 /// The suffix of an integer literal.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Suffix {
     /// The integer's suffix was `u`.
     Unsigned,
     /// The integer's suffix was `i`.
     Signed,
+    /// The integer's suffix was a primitive integer type, such as `usize`.
+    Exact(Primitive),
     /// The integer's suffix was omitted.
     Either,
 }
@@ -38,21 +134,81 @@ pub(crate) struct Integer {
     pub(crate) is_negative: bool,
     /// The value after casting to `u128`.
     pub(crate) raw_value: u128,
-    /// The suffix, whether `u`, `i`, or omitted.
+    /// The suffix, whether `u`, `i`, a primitive integer type, or omitted.
     pub(crate) suffix: Suffix,
     /// The span of the integer literal.
     pub(crate) span: Span,
 }
 
 impl Integer {
+    /// A zero without a suffix, used when the minimum value of a range is omitted. Its type is
+    /// determined by the maximum value.
+    pub(crate) const fn zero(span: Span) -> Self {
+        Self {
+            is_negative: false,
+            raw_value: 0,
+            suffix: Suffix::Either,
+            span,
+        }
+    }
+
+    /// The integer one less than this one, used for the maximum value of an exclusive range.
+    ///
+    /// If the result is too small to be represented, it saturates. This is only possible when
+    /// the range is empty, which is reported elsewhere.
+    pub(crate) const fn pred(self) -> Self {
+        let (is_negative, raw_value) = if self.is_negative {
+            (true, self.raw_value.saturating_add(1))
+        } else if self.raw_value == 0 {
+            (true, 1)
+        } else {
+            (false, self.raw_value - 1)
+        };
+        Self {
+            is_negative,
+            raw_value,
+            ..self
+        }
+    }
+
+    /// Whether the integer is greater than the other.
+    pub(crate) const fn is_greater_than(&self, other: &Self) -> bool {
+        match (self.is_negative, other.is_negative) {
+            (false, true) => !(self.raw_value == 0 && other.raw_value == 0),
+            (true, false) => false,
+            (false, false) => self.raw_value > other.raw_value,
+            (true, true) => self.raw_value < other.raw_value,
+        }
+    }
+
     /// Whether the integer is permitted to be unsigned.
     const fn can_be_unsigned(&self) -> bool {
-        !self.is_negative && matches!(self.suffix, Suffix::Unsigned | Suffix::Either)
+        !self.is_negative
+            && match self.suffix {
+                Suffix::Unsigned | Suffix::Either => true,
+                Suffix::Signed => false,
+                Suffix::Exact(primitive) => !primitive.is_signed(),
+            }
     }
 
     /// Whether the integer is permitted to be signed.
     const fn can_be_signed(&self) -> bool {
-        matches!(self.suffix, Suffix::Signed | Suffix::Either)
+        match self.suffix {
+            Suffix::Signed | Suffix::Either => true,
+            Suffix::Unsigned => false,
+            Suffix::Exact(primitive) => primitive.is_signed(),
+        }
+    }
+
+    /// The value of the integer as an `i128`, if it can be represented.
+    const fn to_i128(&self) -> Option<i128> {
+        if self.is_negative {
+            0_i128.checked_sub_unsigned(self.raw_value)
+        } else if self.raw_value <= i128::MAX as u128 {
+            Some(self.raw_value as i128)
+        } else {
+            None
+        }
     }
 
     /// Attempt to cast the integer to the requested unsigned integer.
@@ -71,16 +227,45 @@ impl Integer {
         T: Signed,
     {
         self.can_be_signed()
-            .then(|| {
-                if self.is_negative {
-                    (-(self.raw_value as i128)).try_into().ok()
-                } else {
-                    (self.raw_value as i128).try_into().ok()
-                }
-            })
+            .then(|| self.to_i128()?.try_into().ok())
             .flatten()
     }
 
+    /// Cast the integer to the requested primitive integer, whose type is `primitive`. `what`
+    /// describes the integer in any error message.
+    pub(crate) fn to_primitive<T>(&self, primitive: Primitive, what: &str) -> Result<T, TokenStream>
+    where
+        T: TryFrom<u128> + TryFrom<i128>,
+    {
+        let is_compatible = match self.suffix {
+            Suffix::Either => true,
+            Suffix::Unsigned => !primitive.is_signed(),
+            Suffix::Signed => primitive.is_signed(),
+            Suffix::Exact(suffix) => suffix == primitive,
+        };
+        if !is_compatible {
+            return Err(compile_error(
+                &format!(
+                    "{what} has a suffix that conflicts with `{}`",
+                    primitive.name()
+                ),
+                self.span,
+            ));
+        }
+
+        let value = if self.is_negative {
+            self.to_i128().and_then(|value| T::try_from(value).ok())
+        } else {
+            T::try_from(self.raw_value).ok()
+        };
+        value.ok_or_else(|| {
+            compile_error(
+                &format!("{what} cannot be represented by `{}`", primitive.name()),
+                self.span,
+            )
+        })
+    }
+
     /// Parse an integer literal from a token stream.
     #[allow(clippy::unwrap_in_result)] // `expect` on negative sign
     pub(crate) fn try_from_tokens(
@@ -138,6 +323,15 @@ impl Integer {
     }
 }
 
+impl core::fmt::Display for Integer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_negative {
+            f.write_str("-")?;
+        }
+        self.raw_value.fmt(f)
+    }
+}
+
 // Returns base 10 digits and suffix.
 fn parse_lit_int(is_negative: bool, s: &str, what: &str) -> Result<(u128, Suffix), String> {
     let s = s.as_bytes();
@@ -174,18 +368,14 @@ fn parse_lit_int(is_negative: bool, s: &str, what: &str) -> Result<(u128, Suffix
         has_digit = true;
 
         // Check for overflow depending on the sign.
-        value = if is_negative {
-            value
-                .checked_mul(base as u128)
-                .and_then(|value| value.checked_add(digit as u128))
-                .ok_or("value too small to be represented by a primitive integer")?
-        } else {
-            (value as i128)
-                .checked_mul(base as i128)
-                .and_then(|value| value.checked_add(digit as i128))
-                .ok_or("value too large to be represented by a primitive integer")?
-                as u128
-        };
+        value = value
+            .checked_mul(base as u128)
+            .and_then(|value| value.checked_add(digit as u128))
+            .ok_or(if is_negative {
+                "value too small to be represented by a primitive integer"
+            } else {
+                "value too large to be represented by a primitive integer"
+            })?;
     }
 
     if !has_digit {
@@ -197,7 +387,18 @@ fn parse_lit_int(is_negative: bool, s: &str, what: &str) -> Result<(u128, Suffix
         b"u" if is_negative => return Err("unsigned integer cannot be negative".to_owned()),
         b"u" => Suffix::Unsigned,
         b"i" => Suffix::Signed,
-        _ => return Err("integer suffix must be `u`, `i`, or omitted".to_owned()),
+        _ => match core::str::from_utf8(s).ok().and_then(Primitive::from_name) {
+            Some(primitive) if is_negative && !primitive.is_signed() => {
+                return Err("unsigned integer cannot be negative".to_owned());
+            }
+            Some(primitive) => Suffix::Exact(primitive),
+            None => {
+                return Err(
+                    "integer suffix must be `u`, `i`, a primitive integer type, or omitted"
+                        .to_owned(),
+                );
+            }
+        },
     };
 
     Ok((value, suffix))
@@ -2,34 +2,70 @@
 
 #![doc(test(attr(deny(warnings))))]
 
+mod expr;
 mod helpers;
 mod integer;
+mod repr;
 mod ty;
+mod value;
 
-use proc_macro::TokenStream;
+use proc_macro::{Punct, Spacing, TokenStream, TokenTree};
 
-use crate::helpers::{parse_comma, unwrap_or_return};
+use crate::helpers::{parse_separator, unwrap_or_return, Separator};
 use crate::integer::Integer;
 use crate::ty::Type;
 
+/// Parse the minimum and maximum values, provided either as `min, max` or as a range.
+fn parse_bounds(input: TokenStream) -> Result<(Integer, Integer), TokenStream> {
+    let mut iter = input.into_iter();
+
+    // A range without a lower bound starts at zero.
+    let min = match iter.clone().next() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '.' => Integer::zero(punct.span()),
+        _ => Integer::try_from_tokens(&mut iter, "minimum value")?,
+    };
+    let separator = parse_separator(&mut iter)?;
+    let max = Integer::try_from_tokens(&mut iter, "maximum value")?;
+
+    if separator == Separator::RangeExclusive {
+        Ok((min, max.pred()))
+    } else {
+        Ok((min, max))
+    }
+}
+
 #[allow(missing_docs)] // documented in re-export in `deranged`
 #[proc_macro]
 pub fn int(input: TokenStream) -> TokenStream {
-    let mut iter = input.into_iter();
-
-    let min = unwrap_or_return!(Integer::try_from_tokens(&mut iter, "minimum value"));
-    unwrap_or_return!(parse_comma(&mut iter));
-    let max = unwrap_or_return!(Integer::try_from_tokens(&mut iter, "maximum value"));
+    if expr::is_typed(&input) {
+        return unwrap_or_return!(expr::expand::<false>(input));
+    }
+    let (min, max) = unwrap_or_return!(parse_bounds(input));
     unwrap_or_return!(Type::<false>::from_min_max(&min, &max)).into_tokens()
 }
 
 #[allow(missing_docs)] // documented in re-export in `deranged`
 #[proc_macro]
 pub fn opt_int(input: TokenStream) -> TokenStream {
-    let mut iter = input.into_iter();
-
-    let min = unwrap_or_return!(Integer::try_from_tokens(&mut iter, "minimum value"));
-    unwrap_or_return!(parse_comma(&mut iter));
-    let max = unwrap_or_return!(Integer::try_from_tokens(&mut iter, "maximum value"));
+    if expr::is_typed(&input) {
+        return unwrap_or_return!(expr::expand::<true>(input));
+    }
+    let (min, max) = unwrap_or_return!(parse_bounds(input));
     unwrap_or_return!(Type::<true>::from_min_max(&min, &max)).into_tokens()
 }
+
+#[allow(missing_docs)] // documented in re-export in `deranged`
+#[proc_macro]
+pub fn val(input: TokenStream) -> TokenStream {
+    unwrap_or_return!(value::expand(input))
+}
+
+#[allow(missing_docs)] // documented in re-export in `deranged`
+#[proc_macro_derive(RangedRepr)]
+pub fn ranged_repr(input: TokenStream) -> TokenStream {
+    repr::derive(input).unwrap_or_else(|mut err| {
+        // The error is emitted in item position, where it must be followed by a semicolon.
+        err.extend([TokenTree::Punct(Punct::new(';', Spacing::Alone))]);
+        err
+    })
+}
@@ -0,0 +1,430 @@
+//! Implementation of `#[derive(RangedRepr)]`.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use crate::helpers::{compile_error, with_span};
+use crate::integer::{Integer, Primitive, Suffix};
+use crate::ty::Type;
+
+/// A variant of a fieldless enum.
+#[derive(Debug)]
+struct Variant {
+    /// The name of the variant.
+    name: Ident,
+    /// The discriminant of the variant, whether explicit or implicit.
+    discriminant: i128,
+}
+
+/// Derive conversions between a fieldless enum and the ranged integer spanning its discriminants.
+pub(crate) fn derive(input: TokenStream) -> Result<TokenStream, TokenStream> {
+    let mut iter = input.into_iter().peekable();
+
+    // Skip outer attributes other than `#[repr(..)]`, and visibility.
+    let mut repr = None;
+    while let Some(TokenTree::Punct(punct)) = iter.peek() {
+        if punct.as_char() != '#' {
+            break;
+        }
+        iter.next();
+        if let Some(TokenTree::Group(attr)) = iter.next() {
+            repr = repr.or_else(|| parse_repr(attr.stream()));
+        }
+    }
+    if matches!(iter.peek(), Some(TokenTree::Ident(ident)) if ident.to_string() == "pub") {
+        iter.next();
+        // `pub(crate)`, `pub(super)`, etc.
+        iter.next_if(|token| {
+            matches!(token, TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis)
+        });
+    }
+
+    match iter.next() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "enum" => {}
+        Some(token) => {
+            return Err(compile_error(
+                "`RangedRepr` can only be derived for enums",
+                token.span(),
+            ));
+        }
+        None => return Err(compile_error("expected enum", None)),
+    }
+
+    let Some(TokenTree::Ident(name)) = iter.next() else {
+        return Err(compile_error("expected enum name", None));
+    };
+
+    let body = match iter.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group.stream(),
+        Some(token) => {
+            return Err(compile_error(
+                "`RangedRepr` cannot be derived for generic enums",
+                token.span(),
+            ));
+        }
+        None => return Err(compile_error("expected enum body", name.span())),
+    };
+
+    let variants = parse_variants(body)?;
+
+    let (Some(min), Some(max)) = (
+        variants.iter().map(|variant| variant.discriminant).min(),
+        variants.iter().map(|variant| variant.discriminant).max(),
+    ) else {
+        return Err(compile_error(
+            "`RangedRepr` cannot be derived for enums without variants",
+            name.span(),
+        ));
+    };
+    // The ranged integer has the same primitive type as the enum's representation, if specified.
+    let suffix = repr.map_or(Suffix::Either, Suffix::Exact);
+    let to_integer = |value: i128| Integer {
+        is_negative: value < 0,
+        raw_value: value.unsigned_abs(),
+        suffix,
+        span: name.span(),
+    };
+    let ty = Type::<false>::from_min_max(&to_integer(min), &to_integer(max))?.into_tokens();
+
+    Ok(generate(&name, &ty, variants, max.abs_diff(min)))
+}
+
+/// Parse the contents of an attribute, returning the primitive type if it is `#[repr(..)]` with a
+/// primitive integer type.
+fn parse_repr(attr: TokenStream) -> Option<Primitive> {
+    let mut iter = attr.into_iter();
+    match iter.next() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "repr" => {}
+        _ => return None,
+    }
+    let Some(TokenTree::Group(group)) = iter.next() else {
+        return None;
+    };
+    // Other representation hints, such as `C`, may accompany the primitive type.
+    group.stream().into_iter().find_map(|token| match token {
+        TokenTree::Ident(ident) => Primitive::from_name(&ident.to_string()),
+        _ => None,
+    })
+}
+
+/// Parse the variants of a fieldless enum from the contents of its body.
+fn parse_variants(body: TokenStream) -> Result<Vec<Variant>, TokenStream> {
+    let mut variants = Vec::new();
+    let mut next_discriminant = Some(0_i128);
+    let mut iter = body.into_iter().peekable();
+
+    while iter.peek().is_some() {
+        // Skip attributes on the variant.
+        while let Some(TokenTree::Punct(punct)) = iter.peek() {
+            if punct.as_char() != '#' {
+                break;
+            }
+            iter.next();
+            iter.next();
+        }
+
+        let name = match iter.next() {
+            Some(TokenTree::Ident(name)) => name,
+            Some(token) => return Err(compile_error("expected variant", token.span())),
+            None => break,
+        };
+
+        let discriminant = match iter.next() {
+            None => next_discriminant,
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => next_discriminant,
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '=' => {
+                let mut tokens = Vec::new();
+                while let Some(token) = iter.next_if(
+                    |token| !matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','),
+                ) {
+                    tokens.push(token);
+                }
+                iter.next();
+                Some(parse_discriminant(tokens, punct.span())?)
+            }
+            Some(TokenTree::Group(group)) => {
+                return Err(compile_error(
+                    "`RangedRepr` can only be derived for enums without fields",
+                    group.span(),
+                ));
+            }
+            Some(token) => return Err(compile_error("expected `,` or `=`", token.span())),
+        };
+
+        let Some(discriminant) = discriminant else {
+            return Err(compile_error(
+                "discriminant is too large to be represented by a primitive integer",
+                name.span(),
+            ));
+        };
+        next_discriminant = discriminant.checked_add(1);
+        variants.push(Variant { name, discriminant });
+    }
+
+    Ok(variants)
+}
+
+/// Parse an explicit discriminant, which must be an integer literal.
+fn parse_discriminant(tokens: Vec<TokenTree>, eq_span: Span) -> Result<i128, TokenStream> {
+    let (first_span, last_span) = match (tokens.first(), tokens.last()) {
+        (Some(first), Some(last)) => (first.span(), last.span()),
+        _ => return Err(compile_error("expected discriminant", eq_span)),
+    };
+    let error = || {
+        compile_error(
+            "discriminant must be an integer literal",
+            (first_span, last_span),
+        )
+    };
+
+    let mut iter = TokenStream::from_iter(tokens).into_iter();
+    let integer = Integer::try_from_tokens(&mut iter, "discriminant").map_err(|_| error())?;
+    if iter.next().is_some() {
+        return Err(error());
+    }
+    integer.to_signed().ok_or_else(|| {
+        compile_error(
+            "discriminant is too large to be represented by a primitive integer",
+            (first_span, last_span),
+        )
+    })
+}
+
+/// Punctuation of one or more characters, such as `::` or `=>`.
+fn punct(chars: &str) -> impl Iterator<Item = TokenTree> + '_ {
+    let last = chars.len() - 1;
+    chars.chars().enumerate().map(move |(index, ch)| {
+        let spacing = if index == last {
+            Spacing::Alone
+        } else {
+            Spacing::Joint
+        };
+        TokenTree::Punct(Punct::new(ch, spacing))
+    })
+}
+
+/// An identifier with the call-site span.
+fn ident(name: &str) -> TokenTree {
+    TokenTree::Ident(Ident::new(name, Span::call_site()))
+}
+
+/// A group delimited by `delimiter` containing `tokens`.
+fn group(delimiter: Delimiter, tokens: impl IntoIterator<Item = TokenTree>) -> TokenTree {
+    TokenTree::Group(Group::new(delimiter, TokenStream::from_iter(tokens)))
+}
+
+/// A path to an item in `core`, such as `::core::convert::From`.
+fn core_path(segments: &[&str]) -> TokenStream {
+    let mut path = TokenStream::from_iter(punct("::"));
+    path.extend([ident("core")]);
+    for segment in segments {
+        path.extend(punct("::"));
+        path.extend([ident(segment)]);
+    }
+    path
+}
+
+/// The discriminant of a variant as a literal, with the span of the variant's name.
+fn discriminant(variant: &Variant) -> TokenStream {
+    let span = variant.name.span();
+    let literal = TokenTree::Literal(Literal::u128_unsuffixed(
+        variant.discriminant.unsigned_abs(),
+    ));
+    let mut tokens = TokenStream::new();
+    if variant.discriminant < 0 {
+        tokens.extend(punct("-").map(|token| with_span(token, span)));
+    }
+    tokens.extend([with_span(literal, span)]);
+    tokens
+}
+
+/// `#[automatically_derived] impl trait_<arg> for self_ty { items }`
+fn impl_block(
+    trait_: &[&str],
+    arg: TokenStream,
+    self_ty: TokenStream,
+    items: TokenStream,
+) -> TokenStream {
+    let mut tokens = TokenStream::from_iter([
+        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+        group(Delimiter::Bracket, [ident("automatically_derived")]),
+        ident("impl"),
+    ]);
+    tokens.extend(core_path(trait_));
+    tokens.extend(punct("<"));
+    tokens.extend(arg);
+    tokens.extend(punct(">"));
+    tokens.extend([ident("for")]);
+    tokens.extend(self_ty);
+    tokens.extend([group(Delimiter::Brace, items)]);
+    tokens
+}
+
+/// `#[inline] fn method(value: param) -> output { match scrutinee { arms } }`
+fn conversion_fn(
+    method: &str,
+    param: TokenStream,
+    output: TokenStream,
+    scrutinee: TokenStream,
+    arms: TokenStream,
+) -> TokenStream {
+    let mut signature = TokenStream::from_iter([
+        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+        group(Delimiter::Bracket, [ident("inline")]),
+        ident("fn"),
+        ident(method),
+    ]);
+    let mut params = TokenStream::from_iter([ident("value")]);
+    params.extend(punct(":"));
+    params.extend(param);
+    signature.extend([group(Delimiter::Parenthesis, params)]);
+    signature.extend(punct("->"));
+    signature.extend(output);
+
+    let mut body = TokenStream::from_iter([ident("match")]);
+    body.extend(scrutinee);
+    body.extend([group(Delimiter::Brace, arms)]);
+    signature.extend([group(Delimiter::Brace, body)]);
+    signature
+}
+
+/// `pattern => expr,`
+fn match_arm(pattern: TokenStream, expr: TokenStream) -> TokenStream {
+    let mut tokens = pattern;
+    tokens.extend(punct("=>"));
+    tokens.extend(expr);
+    tokens.extend(punct(","));
+    tokens
+}
+
+/// `Self::variant`, or `enum_name::variant` if `enum_name` is provided.
+fn variant_path(enum_name: Option<&Ident>, variant: &Variant) -> TokenStream {
+    let mut tokens = TokenStream::from_iter([enum_name.map_or_else(
+        || ident("Self"),
+        |enum_name| TokenTree::Ident(enum_name.clone()),
+    )]);
+    tokens.extend(punct("::"));
+    tokens.extend([TokenTree::Ident(variant.name.clone())]);
+    tokens
+}
+
+/// `::core::result::Result::variant(value)`
+fn result(variant: &str, value: TokenStream) -> TokenStream {
+    let mut tokens = core_path(&["result", "Result", variant]);
+    tokens.extend([group(Delimiter::Parenthesis, value)]);
+    tokens
+}
+
+/// Generate the conversions between the enum and the ranged integer type `ty`. `width` is the
+/// difference between the largest and smallest discriminants.
+fn generate(
+    enum_name: &Ident,
+    ty: &TokenStream,
+    mut variants: Vec<Variant>,
+    width: u128,
+) -> TokenStream {
+    variants.sort_by_key(|variant| variant.discriminant);
+    let is_contiguous = u128::try_from(variants.len() - 1) == Ok(width);
+
+    let enum_ty = TokenStream::from(TokenTree::Ident(enum_name.clone()));
+    let value = TokenStream::from(ident("value"));
+    let mut get = value.clone();
+    get.extend(punct("."));
+    get.extend([
+        ident("get"),
+        group(Delimiter::Parenthesis, TokenStream::new()),
+    ]);
+
+    // `Enum::Variant => Self::new_static::<{ discriminant }>(),`
+    let arms = variants
+        .iter()
+        .flat_map(|variant| {
+            let mut expr = TokenStream::from(ident("Self"));
+            expr.extend(punct("::"));
+            expr.extend([ident("new_static")]);
+            expr.extend(punct("::<"));
+            expr.extend([group(Delimiter::Brace, discriminant(variant))]);
+            expr.extend(punct(">"));
+            expr.extend([group(Delimiter::Parenthesis, TokenStream::new())]);
+            match_arm(variant_path(Some(enum_name), variant), expr)
+        })
+        .collect();
+    let mut output = impl_block(
+        &["convert", "From"],
+        enum_ty.clone(),
+        ty.clone(),
+        conversion_fn(
+            "from",
+            enum_ty.clone(),
+            TokenStream::from(ident("Self")),
+            value.clone(),
+            arms,
+        ),
+    );
+
+    if is_contiguous {
+        // `discriminant => Self::Variant,`, with the last variant matching any other value, which
+        // cannot occur.
+        let mut arms = TokenStream::new();
+        if let Some((last, rest)) = variants.split_last() {
+            for variant in rest {
+                arms.extend(match_arm(
+                    discriminant(variant),
+                    variant_path(None, variant),
+                ));
+            }
+            arms.extend(match_arm(
+                TokenStream::from(ident("_")),
+                variant_path(None, last),
+            ));
+        }
+        output.extend(impl_block(
+            &["convert", "From"],
+            ty.clone(),
+            enum_ty,
+            conversion_fn(
+                "from",
+                ty.clone(),
+                TokenStream::from(ident("Self")),
+                get,
+                arms,
+            ),
+        ));
+    } else {
+        // `discriminant => ::core::result::Result::Ok(Self::Variant),`
+        let mut arms = TokenStream::new();
+        for variant in &variants {
+            arms.extend(match_arm(
+                discriminant(variant),
+                result("Ok", variant_path(None, variant)),
+            ));
+        }
+        arms.extend(match_arm(
+            TokenStream::from(ident("_")),
+            result("Err", value),
+        ));
+
+        // `::core::result::Result<Self, Self::Error>`
+        let mut return_ty = core_path(&["result", "Result"]);
+        return_ty.extend(punct("<"));
+        return_ty.extend([ident("Self")]);
+        return_ty.extend(punct(","));
+        return_ty.extend([ident("Self")]);
+        return_ty.extend(punct("::"));
+        return_ty.extend([ident("Error")]);
+        return_ty.extend(punct(">"));
+
+        let mut items = TokenStream::from_iter([ident("type"), ident("Error")]);
+        items.extend(punct("="));
+        items.extend(ty.clone());
+        items.extend(punct(";"));
+        items.extend(conversion_fn("try_from", ty.clone(), return_ty, get, arms));
+        output.extend(impl_block(
+            &["convert", "TryFrom"],
+            ty.clone(),
+            enum_ty,
+            items,
+        ));
+    }
+
+    output
+}
@@ -1,9 +1,9 @@
-//! Representation of all fixed-size primitive integers.
+//! Representation of all primitive integers.
 
 use proc_macro::{Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
 use crate::helpers::{compile_error, with_span};
-use crate::Integer;
+use crate::integer::{Integer, Primitive, Suffix};
 
 /// The minimum and maximum values for a primitive integer.
 #[derive(Debug)]
@@ -19,6 +19,8 @@ enum MinMax {
     #[allow(clippy::missing_docs_in_private_items)]
     U128(u128, u128),
     #[allow(clippy::missing_docs_in_private_items)]
+    Usize(u64, u64),
+    #[allow(clippy::missing_docs_in_private_items)]
     I8(i8, i8),
     #[allow(clippy::missing_docs_in_private_items)]
     I16(i16, i16),
@@ -28,6 +30,8 @@ enum MinMax {
     I64(i64, i64),
     #[allow(clippy::missing_docs_in_private_items)]
     I128(i128, i128),
+    #[allow(clippy::missing_docs_in_private_items)]
+    Isize(i64, i64),
 }
 
 /// The spans for the minimum and maximum values in [`MinMax`].
@@ -43,6 +47,17 @@ impl<const OPTIONAL: bool> Type<OPTIONAL> {
     pub(crate) fn from_min_max(min: &Integer, max: &Integer) -> Result<Self, TokenStream> {
         let spans = MinMaxSpan(min.span, max.span);
 
+        if min.is_greater_than(max) {
+            return Err(compile_error(
+                "range is empty: the minimum value is greater than the maximum value",
+                (min.span, max.span),
+            ));
+        }
+
+        if let Some(primitive) = Self::requested_primitive(min, max)? {
+            return Self::from_primitive(primitive, min, max);
+        }
+
         if let (Some(min_value), Some(max_value)) = (min.to_unsigned(), max.to_unsigned()) {
             Ok(Self(MinMax::U8(min_value, max_value), spans))
         } else if let (Some(min_value), Some(max_value)) = (min.to_unsigned(), max.to_unsigned()) {
@@ -71,6 +86,59 @@ impl<const OPTIONAL: bool> Type<OPTIONAL> {
         }
     }
 
+    /// Obtain the primitive integer type explicitly requested by the suffix of either value.
+    fn requested_primitive(min: &Integer, max: &Integer) -> Result<Option<Primitive>, TokenStream> {
+        match (min.suffix, max.suffix) {
+            (Suffix::Exact(min_primitive), Suffix::Exact(max_primitive))
+                if min_primitive != max_primitive =>
+            {
+                Err(compile_error(
+                    "minimum and maximum values have conflicting types",
+                    (min.span, max.span),
+                ))
+            }
+            (Suffix::Exact(primitive), _) | (_, Suffix::Exact(primitive)) => Ok(Some(primitive)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Obtain the type from a minimum-maximum pair that must be represented by `primitive`.
+    ///
+    /// The width of `usize` and `isize` is not known to the macro, so their values are checked
+    /// against the widest supported pointer width. The compiler rejects any that are too large for
+    /// the target.
+    fn from_primitive(
+        primitive: Primitive,
+        min: &Integer,
+        max: &Integer,
+    ) -> Result<Self, TokenStream> {
+        #[allow(clippy::missing_docs_in_private_items)]
+        macro_rules! min_max {
+            ($variant:ident) => {
+                MinMax::$variant(
+                    min.to_primitive(primitive, "minimum value")?,
+                    max.to_primitive(primitive, "maximum value")?,
+                )
+            };
+        }
+
+        let min_max = match primitive {
+            Primitive::U8 => min_max!(U8),
+            Primitive::U16 => min_max!(U16),
+            Primitive::U32 => min_max!(U32),
+            Primitive::U64 => min_max!(U64),
+            Primitive::U128 => min_max!(U128),
+            Primitive::Usize => min_max!(Usize),
+            Primitive::I8 => min_max!(I8),
+            Primitive::I16 => min_max!(I16),
+            Primitive::I32 => min_max!(I32),
+            Primitive::I64 => min_max!(I64),
+            Primitive::I128 => min_max!(I128),
+            Primitive::Isize => min_max!(Isize),
+        };
+        Ok(Self(min_max, MinMaxSpan(min.span, max.span)))
+    }
+
     /// Convert a type into a `TokenStream`.
     pub(crate) fn into_tokens(self) -> TokenStream {
         #[allow(clippy::missing_docs_in_private_items)]
@@ -113,6 +181,11 @@ impl<const OPTIONAL: bool> Type<OPTIONAL> {
                 Literal::u128_unsuffixed(min),
                 Literal::u128_unsuffixed(max),
             ),
+            MinMax::Usize(min, max) => (
+                maybe_optional!("RangedUsize"),
+                Literal::u64_unsuffixed(min),
+                Literal::u64_unsuffixed(max),
+            ),
             MinMax::I8(min, max) => (
                 maybe_optional!("RangedI8"),
                 Literal::i8_unsuffixed(min),
@@ -138,6 +211,11 @@ impl<const OPTIONAL: bool> Type<OPTIONAL> {
                 Literal::i128_unsuffixed(min),
                 Literal::i128_unsuffixed(max),
             ),
+            MinMax::Isize(min, max) => (
+                maybe_optional!("RangedIsize"),
+                Literal::i64_unsuffixed(min),
+                Literal::i64_unsuffixed(max),
+            ),
         };
 
         TokenStream::from_iter([
@@ -0,0 +1,71 @@
+//! Implementation of `val!`.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, TokenStream, TokenTree};
+
+use crate::expr::{self, Bounds};
+use crate::helpers::{compile_error, with_span};
+use crate::integer::{Integer, Suffix};
+use crate::parse_bounds;
+
+/// Expand `value in min..=max` (or any other bounds accepted by `int!`) to a ranged integer with
+/// the provided value, checking the value against the bounds if they are literals.
+pub(crate) fn expand(input: TokenStream) -> Result<TokenStream, TokenStream> {
+    let mut iter = input.into_iter();
+
+    let value = Integer::try_from_tokens(&mut iter, "value")?;
+    if value.suffix != Suffix::Either {
+        return Err(compile_error(
+            "value cannot have a suffix, as its type is determined by the range",
+            value.span,
+        ));
+    }
+
+    match iter.next() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "in" => {}
+        Some(token) => return Err(compile_error("expected `in`", token.span())),
+        None => return Err(compile_error("expected `in`", value.span)),
+    }
+
+    let bounds: TokenStream = iter.collect();
+    let bounds = if expr::is_typed(&bounds) {
+        expr::parse_bounds(bounds)?
+    } else {
+        let (min, max) = parse_bounds(bounds)?;
+        Bounds::Literal(min, max)
+    };
+
+    // Bounds that are const expressions are checked against the value by `new_static`.
+    if let Bounds::Literal(min, max) = &bounds {
+        if min.is_greater_than(&value) || value.is_greater_than(max) {
+            return Err(compile_error(
+                &format!("value is not in the range {min}..={max}"),
+                value.span,
+            ));
+        }
+    }
+
+    let mut output = bounds.into_tokens::<false>()?;
+    output.extend([
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("new_static", value.span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+    ]);
+    if value.is_negative {
+        output.extend([with_span(
+            TokenTree::Punct(Punct::new('-', Spacing::Alone)),
+            value.span,
+        )]);
+    }
+    output.extend([
+        with_span(
+            TokenTree::Literal(Literal::u128_unsuffixed(value.raw_value)),
+            value.span,
+        ),
+        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+    ]);
+    Ok(output)
+}
@@ -0,0 +1,36 @@
+//! Declaration of `AllValues`, an iterator over every value of a ranged integer type.
+
+/// An iterator over every value of a ranged integer type, in ascending order.
+///
+/// This struct is created by the `all` method on ranged integer types, such as
+/// [`RangedU8::all`](crate::RangedU8::all).
+///
+/// # Example
+///
+/// ```rust
+/// # use deranged::RangedU8;
+/// let months = RangedU8::<1, 12>::all();
+/// assert_eq!(months.len(), 12);
+/// assert_eq!(months.clone().nth(2).map(|month| month.get()), Some(3));
+/// assert_eq!(months.rev().next().map(|month| month.get()), Some(12));
+/// ```
+///
+/// `AllValues` implements [`ExactSizeIterator`] for every ranged integer type, but calling
+/// [`len`](ExactSizeIterator::len) **fails to compile** if the number of values in the range does
+/// not fit in a `usize`. This is only possible when the primitive type is at least as wide as
+/// `usize`. [`size_hint`](Iterator::size_hint) may be used for such ranges instead.
+///
+/// ```rust,compile_fail
+/// # use deranged::RangedU64;
+/// let _ = RangedU64::<0, { u64::MAX }>::all().len();
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct AllValues<T> {
+    /// The next value to be yielded from the front.
+    pub(crate) front: T,
+    /// The next value to be yielded from the back.
+    pub(crate) back: T,
+    /// Whether every value has been yielded. Necessary as `front` and `back` cannot cross.
+    pub(crate) exhausted: bool,
+}
@@ -0,0 +1,185 @@
+//! The bounds of the results of arithmetic on ranged integers, computed in `const` contexts.
+//!
+//! These functions are used to statically check the output range of the `*_ranged` methods and, if
+//! the `unstable-generic-const-exprs` feature is enabled, to infer the output range of operators.
+//! All of them **panic** if a bound cannot be represented by the primitive type; arithmetic is
+//! checked explicitly, as a `const fn` does not check for overflow when overflow checks are
+//! disabled.
+
+/// The smaller of two values, usable in `const` contexts.
+macro_rules! const_min {
+    ($a:expr, $b:expr) => {
+        if $a < $b {
+            $a
+        } else {
+            $b
+        }
+    };
+}
+
+/// The larger of two values, usable in `const` contexts.
+macro_rules! const_max {
+    ($a:expr, $b:expr) => {
+        if $a > $b {
+            $a
+        } else {
+            $b
+        }
+    };
+}
+
+/// Unwrap the result of checked arithmetic, panicking with the provided message on overflow.
+macro_rules! const_expect {
+    ($e:expr, $message:literal) => {
+        match $e {
+            Some(value) => value,
+            None => panic!($message),
+        }
+    };
+}
+
+/// Implement the bounds computations for the provided primitive integer types.
+macro_rules! impl_bounds {
+    ($($internal:ident $signedness:ident)*) => {$(
+        #[doc = concat!("Bounds of the results of arithmetic on `", stringify!($internal), "`.")]
+        pub mod $internal {
+            /// The smallest product of a value in `min..=max` and a value in `rhs_min..=rhs_max`.
+            #[inline]
+            pub const fn mul_min(
+                min: $internal,
+                max: $internal,
+                rhs_min: $internal,
+                rhs_max: $internal,
+            ) -> $internal {
+                let (a, b, c, d) = mul_corners(min, max, rhs_min, rhs_max);
+                const_min!(const_min!(a, b), const_min!(c, d))
+            }
+
+            /// The largest product of a value in `min..=max` and a value in `rhs_min..=rhs_max`.
+            #[inline]
+            pub const fn mul_max(
+                min: $internal,
+                max: $internal,
+                rhs_min: $internal,
+                rhs_max: $internal,
+            ) -> $internal {
+                let (a, b, c, d) = mul_corners(min, max, rhs_min, rhs_max);
+                const_max!(const_max!(a, b), const_max!(c, d))
+            }
+
+            /// The products of the bounds of the two ranges. The extrema of the product are always
+            /// among these.
+            #[inline]
+            const fn mul_corners(
+                min: $internal,
+                max: $internal,
+                rhs_min: $internal,
+                rhs_max: $internal,
+            ) -> ($internal, $internal, $internal, $internal) {
+                (
+                    const_expect!(min.checked_mul(rhs_min), "the product may overflow"),
+                    const_expect!(min.checked_mul(rhs_max), "the product may overflow"),
+                    const_expect!(max.checked_mul(rhs_min), "the product may overflow"),
+                    const_expect!(max.checked_mul(rhs_max), "the product may overflow"),
+                )
+            }
+
+            /// The largest remainder of a value in `..=max` divided by a value in
+            /// `rhs_min..=rhs_max`.
+            ///
+            /// The remainder has the sign of the dividend and a magnitude smaller than that of the
+            /// divisor.
+            #[inline]
+            pub const fn rem_max(
+                max: $internal,
+                rhs_min: $internal,
+                rhs_max: $internal,
+            ) -> $internal {
+                // The divisor must be checked even when the result does not depend on it.
+                let magnitude = rem_magnitude(rhs_min, rhs_max);
+                if max <= 0 { 0 } else { const_min!(max, magnitude) }
+            }
+
+            impl_bounds!(@$signedness $internal);
+        }
+    )*};
+
+    (@unsigned $internal:ident) => {
+        /// The largest remainder of division by a value in `rhs_min..=rhs_max`.
+        #[inline]
+        const fn rem_magnitude(rhs_min: $internal, rhs_max: $internal) -> $internal {
+            if rhs_min == 0 {
+                panic!("the divisor may be zero");
+            }
+            rhs_max - 1
+        }
+
+        /// The smallest remainder of a value in `min..` divided by a value in `rhs_min..=rhs_max`.
+        /// This is always zero.
+        #[inline]
+        pub const fn rem_min(_min: $internal, rhs_min: $internal, rhs_max: $internal) -> $internal {
+            // The divisor must still be checked, even though the result does not depend on it.
+            let _ = rem_magnitude(rhs_min, rhs_max);
+            0
+        }
+    };
+
+    (@signed $internal:ident) => {
+        /// The largest magnitude of the remainder of division by a value in `rhs_min..=rhs_max`.
+        #[inline]
+        const fn rem_magnitude(rhs_min: $internal, rhs_max: $internal) -> $internal {
+            if rhs_min <= 0 && rhs_max >= 0 {
+                panic!("the divisor may be zero");
+            }
+            // The negation cannot overflow, as `rhs_min + 1` is greater than the minimum value.
+            if rhs_min > 0 { rhs_max - 1 } else { -(rhs_min + 1) }
+        }
+
+        /// The smallest remainder of a value in `min..` divided by a value in `rhs_min..=rhs_max`.
+        ///
+        /// The remainder has the sign of the dividend and a magnitude smaller than that of the
+        /// divisor.
+        #[inline]
+        pub const fn rem_min(min: $internal, rhs_min: $internal, rhs_max: $internal) -> $internal {
+            // The divisor must be checked even when the result does not depend on it. The negation
+            // cannot overflow, as the magnitude is never the minimum value.
+            let magnitude = rem_magnitude(rhs_min, rhs_max);
+            if min >= 0 { 0 } else { const_max!(min, -magnitude) }
+        }
+
+        /// The smallest absolute value of a value in `min..=max`.
+        #[inline]
+        pub const fn abs_min(min: $internal, max: $internal) -> $internal {
+            if min > 0 {
+                min
+            } else if max < 0 {
+                const_expect!(max.checked_neg(), "the absolute value may overflow")
+            } else {
+                0
+            }
+        }
+
+        /// The largest absolute value of a value in `min..=max`.
+        #[inline]
+        pub const fn abs_max(min: $internal, max: $internal) -> $internal {
+            let min = const_expect!(min.checked_abs(), "the absolute value may overflow");
+            let max = const_expect!(max.checked_abs(), "the absolute value may overflow");
+            const_max!(min, max)
+        }
+    };
+}
+
+impl_bounds! {
+    u8 unsigned
+    u16 unsigned
+    u32 unsigned
+    u64 unsigned
+    u128 unsigned
+    usize unsigned
+    i8 signed
+    i16 signed
+    i32 signed
+    i64 signed
+    i128 signed
+    isize signed
+}
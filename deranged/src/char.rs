@@ -0,0 +1,403 @@
+//! Ranged characters.
+
+use core::error::Error;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+use crate::unsafe_wrapper::Unsafe;
+use crate::{AllValues, RangedU32};
+
+/// The first code point after the surrogate range, which cannot be represented by `char`.
+const AFTER_SURROGATES: u32 = 0xE000;
+/// The number of code points in the surrogate range.
+const SURROGATE_COUNT: u32 = 0x800;
+
+/// A `char` that is known to be in the range `MIN..=MAX`.
+///
+/// # Example
+///
+/// ```rust
+/// # use deranged::RangedChar;
+/// type DriveLetter = RangedChar<'A', 'Z'>;
+///
+/// let drive: DriveLetter = "C".parse().expect("value is in range");
+/// assert_eq!(drive.get(), 'C');
+/// assert!("c".parse::<DriveLetter>().is_err());
+/// assert_eq!(DriveLetter::all().count(), 26);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RangedChar<const MIN: char, const MAX: char>(Unsafe<char>);
+
+impl<const MIN: char, const MAX: char> RangedChar<MIN, MAX> {
+    /// The smallest value that can be represented by this type.
+    // Safety: `MIN` is in range by definition.
+    pub const MIN: Self = Self::new_static::<MIN>();
+
+    /// The largest value that can be represented by this type.
+    // Safety: `MAX` is in range by definition.
+    pub const MAX: Self = Self::new_static::<MAX>();
+
+    /// Returns an iterator over every value of this type, in ascending order.
+    #[inline]
+    pub const fn all() -> AllValues<Self> {
+        static_assert!(MIN <= MAX);
+        AllValues {
+            front: Self::MIN,
+            back: Self::MAX,
+            exhausted: false,
+        }
+    }
+
+    /// Creates a ranged char without checking the value.
+    ///
+    /// # Safety
+    ///
+    /// The value must be within the range `MIN..=MAX`.
+    #[track_caller]
+    #[inline(always)]
+    pub const unsafe fn new_unchecked(value: char) -> Self {
+        static_assert!(MIN <= MAX);
+        // Safety: The caller must ensure that the value is in range.
+        unsafe {
+            core::hint::assert_unchecked(MIN <= value && value <= MAX);
+            Self(Unsafe::new(value))
+        }
+    }
+
+    /// Returns the value as a primitive type.
+    #[track_caller]
+    #[inline(always)]
+    pub const fn get(self) -> char {
+        static_assert!(MIN <= MAX);
+        let value = *self.0.get();
+        // Safety: A stored value is always in range.
+        unsafe { core::hint::assert_unchecked(MIN <= value && value <= MAX) };
+        value
+    }
+
+    /// Creates a ranged char if the given value is in the range `MIN..=MAX`.
+    #[inline(always)]
+    pub const fn new(value: char) -> Option<Self> {
+        static_assert!(MIN <= MAX);
+        if value < MIN || value > MAX {
+            None
+        } else {
+            // Safety: The value is in range.
+            Some(unsafe { Self::new_unchecked(value) })
+        }
+    }
+
+    /// Creates a ranged char with a statically known value. **Fails to compile** if the value is
+    /// not in range.
+    #[inline(always)]
+    pub const fn new_static<const VALUE: char>() -> Self {
+        static_assert!(MIN <= VALUE);
+        static_assert!(VALUE <= MAX);
+        // Safety: The value is in range.
+        unsafe { Self::new_unchecked(VALUE) }
+    }
+
+    /// Creates a ranged char with the given value, saturating if it is out of range.
+    #[inline]
+    pub const fn new_saturating(value: char) -> Self {
+        static_assert!(MIN <= MAX);
+        if value < MIN {
+            Self::MIN
+        } else if value > MAX {
+            Self::MAX
+        } else {
+            // Safety: The value is in range.
+            unsafe { Self::new_unchecked(value) }
+        }
+    }
+
+    /// Converts a digit in the given radix to a ranged char, returning `None` if the digit is not
+    /// valid in the radix or the resulting char is not in range.
+    ///
+    /// Digits greater than nine are converted to lowercase letters, as with [`char::from_digit`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    #[inline]
+    pub const fn from_digit(num: u32, radix: u32) -> Option<Self> {
+        static_assert!(MIN <= MAX);
+        match char::from_digit(num, radix) {
+            Some(value) => Self::new(value),
+            None => None,
+        }
+    }
+
+    /// Converts the char to a digit in the given radix, returning `None` if it is not a valid
+    /// digit in the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    #[inline]
+    pub const fn to_digit(self, radix: u32) -> Option<u32> {
+        static_assert!(MIN <= MAX);
+        self.get().to_digit(radix)
+    }
+
+    /// Converts the char to a ranged integer of its code point. **Fails to compile** if the range
+    /// of the integer is not a superset of the code points in this range.
+    ///
+    /// ```rust
+    /// # use deranged::{RangedChar, RangedU32};
+    /// let letter = RangedChar::<'A', 'Z'>::new_static::<'C'>();
+    /// let code_point: RangedU32<0x41, 0x5A> = letter.to_ranged_u32();
+    /// assert_eq!(code_point.get(), 0x43);
+    /// ```
+    #[inline]
+    pub const fn to_ranged_u32<const NEW_MIN: u32, const NEW_MAX: u32>(
+        self,
+    ) -> RangedU32<NEW_MIN, NEW_MAX> {
+        static_assert!(MIN <= MAX);
+        static_assert!(NEW_MIN <= MIN as u32);
+        static_assert!(NEW_MAX >= MAX as u32);
+        // Safety: The range is a superset of the code points in this range.
+        unsafe { RangedU32::new_unchecked(self.get() as u32) }
+    }
+
+    /// The value after this one, skipping the surrogate range. The value must be less than `MAX`.
+    const unsafe fn succ_unchecked(self) -> Self {
+        let value = self.get() as u32 + 1;
+        let value = if value == AFTER_SURROGATES - SURROGATE_COUNT {
+            AFTER_SURROGATES
+        } else {
+            value
+        };
+        // Safety: The value is a valid char that is at most `MAX`, as the caller guarantees that
+        // `self < MAX`.
+        unsafe { Self::new_unchecked(char::from_u32_unchecked(value)) }
+    }
+
+    /// The value before this one, skipping the surrogate range. The value must be greater than
+    /// `MIN`.
+    const unsafe fn pred_unchecked(self) -> Self {
+        let value = self.get() as u32;
+        let value = if value == AFTER_SURROGATES {
+            AFTER_SURROGATES - SURROGATE_COUNT - 1
+        } else {
+            value - 1
+        };
+        // Safety: The value is a valid char that is at least `MIN`, as the caller guarantees that
+        // `self > MIN`.
+        unsafe { Self::new_unchecked(char::from_u32_unchecked(value)) }
+    }
+}
+
+impl<const MIN: char, const MAX: char> fmt::Debug for RangedChar<MIN, MAX> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        static_assert!(MIN <= MAX);
+        self.get().fmt(f)
+    }
+}
+
+impl<const MIN: char, const MAX: char> fmt::Display for RangedChar<MIN, MAX> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        static_assert!(MIN <= MAX);
+        self.get().fmt(f)
+    }
+}
+
+impl<const MIN: char, const MAX: char> From<RangedChar<MIN, MAX>> for char {
+    #[inline(always)]
+    fn from(value: RangedChar<MIN, MAX>) -> Self {
+        static_assert!(MIN <= MAX);
+        value.get()
+    }
+}
+
+impl<const MIN: char, const MAX: char> From<RangedChar<MIN, MAX>> for u32 {
+    #[inline(always)]
+    fn from(value: RangedChar<MIN, MAX>) -> Self {
+        static_assert!(MIN <= MAX);
+        value.get().into()
+    }
+}
+
+impl<const MIN: char, const MAX: char> FromStr for RangedChar<MIN, MAX> {
+    type Err = ParseCharError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static_assert!(MIN <= MAX);
+        let mut chars = s.chars();
+        let (Some(value), None) = (chars.next(), chars.next()) else {
+            return Err(ParseCharError {
+                value: None,
+                min: MIN,
+                max: MAX,
+            });
+        };
+        Self::new(value).ok_or(ParseCharError {
+            value: Some(value),
+            min: MIN,
+            max: MAX,
+        })
+    }
+}
+
+impl<const MIN: char, const MAX: char> AllValues<RangedChar<MIN, MAX>> {
+    /// The number of values remaining.
+    #[inline]
+    const fn remaining(&self) -> u32 {
+        if self.exhausted {
+            return 0;
+        }
+        let front = self.front.get() as u32;
+        let back = self.back.get() as u32;
+        let len = back - front + 1;
+        if front < AFTER_SURROGATES && back >= AFTER_SURROGATES {
+            len - SURROGATE_COUNT
+        } else {
+            len
+        }
+    }
+}
+
+impl<const MIN: char, const MAX: char> Iterator for AllValues<RangedChar<MIN, MAX>> {
+    type Item = RangedChar<MIN, MAX>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        let value = self.front;
+        if value == self.back {
+            self.exhausted = true;
+        } else {
+            // Safety: `value < back <= MAX`.
+            self.front = unsafe { value.succ_unchecked() };
+        }
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<const MIN: char, const MAX: char> DoubleEndedIterator for AllValues<RangedChar<MIN, MAX>> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        let value = self.back;
+        if value == self.front {
+            self.exhausted = true;
+        } else {
+            // Safety: `value > front >= MIN`.
+            self.back = unsafe { value.pred_unchecked() };
+        }
+        Some(value)
+    }
+}
+
+impl<const MIN: char, const MAX: char> FusedIterator for AllValues<RangedChar<MIN, MAX>> {}
+
+/// An error which can be returned when parsing a ranged char.
+///
+/// # Example
+///
+/// ```rust
+/// # use deranged::RangedChar;
+/// let err = "z".parse::<RangedChar<'a', 'f'>>().unwrap_err();
+/// assert_eq!(err.value(), Some('z'));
+/// assert_eq!(err.to_string(), "'z' is not in the range 'a'..='f'");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseCharError {
+    /// The parsed value that was not in range, if the string was a single char.
+    value: Option<char>,
+    /// The minimum value of the range.
+    min: char,
+    /// The maximum value of the range.
+    max: char,
+}
+
+impl ParseCharError {
+    /// The parsed value that was not in range.
+    ///
+    /// Returns `None` if the string did not consist of exactly one char.
+    #[inline(always)]
+    pub const fn value(&self) -> Option<char> {
+        self.value
+    }
+
+    /// The minimum value of the range.
+    #[inline(always)]
+    pub const fn min(&self) -> char {
+        self.min
+    }
+
+    /// The maximum value of the range.
+    #[inline(always)]
+    pub const fn max(&self) -> char {
+        self.max
+    }
+}
+
+impl fmt::Display for ParseCharError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            Some(value) => write!(
+                f,
+                "'{value}' is not in the range '{}'..='{}'",
+                self.min, self.max
+            ),
+            None => f.write_str("string must contain exactly one char"),
+        }
+    }
+}
+
+impl Error for ParseCharError {}
+
+#[cfg(feature = "serde")]
+impl<const MIN: char, const MAX: char> serde_core::Serialize for RangedChar<MIN, MAX> {
+    #[inline(always)]
+    fn serialize<S: serde_core::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        static_assert!(MIN <= MAX);
+        self.get().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const MIN: char, const MAX: char> serde_core::Deserialize<'de> for RangedChar<MIN, MAX> {
+    #[inline]
+    fn deserialize<D: serde_core::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        static_assert!(MIN <= MAX);
+        let value = char::deserialize(deserializer)?;
+        Self::new(value).ok_or_else(|| {
+            <D::Error as serde_core::de::Error>::invalid_value(
+                serde_core::de::Unexpected::Char(value),
+                #[cfg(feature = "alloc")]
+                {
+                    &alloc::format!("a char in the range '{MIN}'..='{MAX}'").as_ref()
+                },
+                #[cfg(not(feature = "alloc"))]
+                {
+                    &"a char in the valid range"
+                },
+            )
+        })
+    }
+}
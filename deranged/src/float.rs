@@ -0,0 +1,406 @@
+//! Ranged floating-point numbers.
+//!
+//! These types have the same integrations as the ranged integers, except for `powerfmt`:
+//! `SmartDisplay` is not implemented for the primitive floats, which the integer implementations
+//! delegate to.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::hint::assert_unchecked;
+#[cfg(any(feature = "rand08", feature = "rand09", feature = "rand010"))]
+use core::ops::RangeInclusive;
+
+use crate::unsafe_wrapper::Unsafe;
+
+/// Implement a ranged floating-point type for each primitive float.
+macro_rules! impl_ranged_float {
+    ($(
+        $type:ident {
+            internal: $internal:ident
+            bits: $bits:ident
+        }
+    )*) => {$(
+        #[doc = concat!(
+            "A finite `",
+            stringify!($internal),
+            "` that is known to be in the range `MIN..=MAX`.",
+        )]
+        ///
+        /// Floating-point const generics are not stable, so the bounds are given as bit patterns,
+        #[doc = concat!("as returned by [`", stringify!($internal), "::to_bits`].")]
+        /// The bounds must be finite and `MIN` must not be greater than `MAX`, otherwise any use of
+        /// the type **fails to compile**.
+        ///
+        /// The value is never NaN, which makes the ordering total. Negative zero is stored as
+        /// positive zero, so that equal values always have the same hash.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("# use deranged::", stringify!($type), ";")]
+        #[doc = concat!(
+            "type Percent = ",
+            stringify!($type),
+            "<{ 0.0_",
+            stringify!($internal),
+            ".to_bits() }, { 100.0_",
+            stringify!($internal),
+            ".to_bits() }>;",
+        )]
+        ///
+        /// let value = Percent::new(42.5).expect("value is in range");
+        /// assert_eq!(value.get(), 42.5);
+        #[doc = concat!("assert!(Percent::new(", stringify!($internal), "::NAN).is_none());")]
+        /// assert_eq!(value.saturating_add(100.0), Percent::MAX);
+        /// ```
+        ///
+        /// Bounds that are not finite are rejected.
+        ///
+        /// ```rust,compile_fail
+        #[doc = concat!("# use deranged::", stringify!($type), ";")]
+        #[doc = concat!(
+            "type Unbounded = ",
+            stringify!($type),
+            "<{ 0.0_",
+            stringify!($internal),
+            ".to_bits() }, { ",
+            stringify!($internal),
+            "::INFINITY.to_bits() }>;",
+        )]
+        /// let _ = Unbounded::new(1.0);
+        /// ```
+        #[repr(transparent)]
+        #[derive(Clone, Copy)]
+        pub struct $type<const MIN: $bits, const MAX: $bits>(Unsafe<$internal>);
+
+        impl<const MIN: $bits, const MAX: $bits> $type<MIN, MAX> {
+            /// The minimum value as a primitive. Evaluating this asserts that the bounds are
+            /// valid.
+            const MIN_VALUE: $internal = {
+                let min = $internal::from_bits(MIN);
+                let max = $internal::from_bits(MAX);
+                assert!(min.is_finite(), "the minimum value must be finite");
+                assert!(max.is_finite(), "the maximum value must be finite");
+                assert!(
+                    min <= max,
+                    "the minimum value must not be greater than the maximum value"
+                );
+                min
+            };
+
+            /// The maximum value as a primitive. Evaluating this asserts that the bounds are
+            /// valid.
+            const MAX_VALUE: $internal = {
+                let _ = Self::MIN_VALUE;
+                $internal::from_bits(MAX)
+            };
+
+            /// The smallest value that can be represented by this type.
+            // Safety: `MIN` is in range by definition.
+            pub const MIN: Self = unsafe { Self::new_unchecked(Self::MIN_VALUE) };
+
+            /// The largest value that can be represented by this type.
+            // Safety: `MAX` is in range by definition.
+            pub const MAX: Self = unsafe { Self::new_unchecked(Self::MAX_VALUE) };
+
+            /// Creates a ranged float without checking the value.
+            ///
+            /// # Safety
+            ///
+            /// The value must be within the range `MIN..=MAX`. In particular, it must not be NaN.
+            #[track_caller]
+            #[inline(always)]
+            pub const unsafe fn new_unchecked(value: $internal) -> Self {
+                // Safety: The caller must ensure that the value is in range.
+                unsafe {
+                    assert_unchecked(Self::MIN_VALUE <= value && value <= Self::MAX_VALUE);
+                    // Adding positive zero turns negative zero into positive zero and leaves all
+                    // other values unchanged.
+                    Self(Unsafe::new(value + 0.0))
+                }
+            }
+
+            /// Returns the value as a primitive type.
+            #[track_caller]
+            #[inline(always)]
+            pub const fn get(self) -> $internal {
+                let value = *self.0.get();
+                // Safety: A stored value is always in range.
+                unsafe { assert_unchecked(Self::MIN_VALUE <= value && value <= Self::MAX_VALUE) };
+                value
+            }
+
+            /// Creates a ranged float if the given value is in the range `MIN..=MAX`. NaN is never
+            /// in range.
+            #[inline(always)]
+            pub const fn new(value: $internal) -> Option<Self> {
+                if Self::MIN_VALUE <= value && value <= Self::MAX_VALUE {
+                    // Safety: The value is in range.
+                    Some(unsafe { Self::new_unchecked(value) })
+                } else {
+                    None
+                }
+            }
+
+            /// Creates a ranged float with the given value, saturating if it is out of range.
+            ///
+            /// NaN has no position relative to the range, so it saturates to `MIN`.
+            #[inline]
+            pub const fn new_saturating(value: $internal) -> Self {
+                if value.is_nan() || value < Self::MIN_VALUE {
+                    Self::MIN
+                } else if value > Self::MAX_VALUE {
+                    Self::MAX
+                } else {
+                    // Safety: The value is in range.
+                    unsafe { Self::new_unchecked(value) }
+                }
+            }
+
+            /// Checked addition. Computes `self + rhs`, returning `None` if the resulting value is
+            /// out of range or NaN.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn checked_add(self, rhs: $internal) -> Option<Self> {
+                Self::new(self.get() + rhs)
+            }
+
+            /// Checked subtraction. Computes `self - rhs`, returning `None` if the resulting value
+            /// is out of range or NaN.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn checked_sub(self, rhs: $internal) -> Option<Self> {
+                Self::new(self.get() - rhs)
+            }
+
+            /// Checked multiplication. Computes `self * rhs`, returning `None` if the resulting
+            /// value is out of range or NaN.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn checked_mul(self, rhs: $internal) -> Option<Self> {
+                Self::new(self.get() * rhs)
+            }
+
+            /// Checked division. Computes `self / rhs`, returning `None` if the resulting value is
+            /// out of range or NaN.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn checked_div(self, rhs: $internal) -> Option<Self> {
+                Self::new(self.get() / rhs)
+            }
+
+            /// Saturating addition. Computes `self + rhs`, saturating at the bounds. A NaN result
+            /// saturates to `MIN`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn saturating_add(self, rhs: $internal) -> Self {
+                Self::new_saturating(self.get() + rhs)
+            }
+
+            /// Saturating subtraction. Computes `self - rhs`, saturating at the bounds. A NaN
+            /// result saturates to `MIN`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn saturating_sub(self, rhs: $internal) -> Self {
+                Self::new_saturating(self.get() - rhs)
+            }
+
+            /// Saturating multiplication. Computes `self * rhs`, saturating at the bounds. A NaN
+            /// result saturates to `MIN`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn saturating_mul(self, rhs: $internal) -> Self {
+                Self::new_saturating(self.get() * rhs)
+            }
+
+            /// Saturating division. Computes `self / rhs`, saturating at the bounds. A NaN result
+            /// saturates to `MIN`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn saturating_div(self, rhs: $internal) -> Self {
+                Self::new_saturating(self.get() / rhs)
+            }
+        }
+
+        impl<const MIN: $bits, const MAX: $bits> PartialEq for $type<MIN, MAX> {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                self.get().to_bits() == other.get().to_bits()
+            }
+        }
+
+        impl<const MIN: $bits, const MAX: $bits> Eq for $type<MIN, MAX> {}
+
+        impl<const MIN: $bits, const MAX: $bits> PartialOrd for $type<MIN, MAX> {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<const MIN: $bits, const MAX: $bits> Ord for $type<MIN, MAX> {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().total_cmp(&other.get())
+            }
+        }
+
+        impl<const MIN: $bits, const MAX: $bits> Hash for $type<MIN, MAX> {
+            #[inline(always)]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.get().to_bits().hash(state);
+            }
+        }
+
+        impl<const MIN: $bits, const MAX: $bits> fmt::Debug for $type<MIN, MAX> {
+            #[inline(always)]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.get().fmt(f)
+            }
+        }
+
+        impl<const MIN: $bits, const MAX: $bits> fmt::Display for $type<MIN, MAX> {
+            #[inline(always)]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.get().fmt(f)
+            }
+        }
+
+        impl<const MIN: $bits, const MAX: $bits> From<$type<MIN, MAX>> for $internal {
+            #[inline(always)]
+            fn from(value: $type<MIN, MAX>) -> Self {
+                value.get()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<const MIN: $bits, const MAX: $bits> serde_core::Serialize for $type<MIN, MAX> {
+            #[inline(always)]
+            fn serialize<S: serde_core::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            {
+                self.get().serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, const MIN: $bits, const MAX: $bits> serde_core::Deserialize<'de>
+            for $type<MIN, MAX>
+        {
+            #[inline]
+            fn deserialize<D: serde_core::Deserializer<'de>>(deserializer: D)
+                -> Result<Self, D::Error>
+            {
+                let internal = <$internal>::deserialize(deserializer)?;
+                Self::new(internal).ok_or_else(||
+                    <D::Error as serde_core::de::Error>::invalid_value(
+                        serde_core::de::Unexpected::Float(internal.into()),
+                        #[cfg(feature = "alloc")] {
+                            &alloc::format!(
+                                "a number in the range {:?}..={:?}",
+                                Self::MIN_VALUE,
+                                Self::MAX_VALUE,
+                            ).as_ref()
+                        },
+                        #[cfg(not(feature = "alloc"))] {
+                            &"a number in the valid range"
+                        }
+                    )
+                )
+            }
+        }
+
+        #[cfg(any(feature = "rand08", feature = "rand09", feature = "rand010"))]
+        impl<const MIN: $bits, const MAX: $bits> $type<MIN, MAX> {
+            /// The range to sample a random value from and the factor to multiply the sample by.
+            /// `rand` cannot sample a range whose width is close to overflowing, so the bounds of
+            /// such a range are divided by four. Scaling by a power of two preserves the
+            /// distribution, other than for subnormal values.
+            #[inline]
+            fn sample_range() -> (RangeInclusive<$internal>, $internal) {
+                if Self::MAX_VALUE - Self::MIN_VALUE <= $internal::MAX / 2.0 {
+                    (Self::MIN_VALUE..=Self::MAX_VALUE, 1.0)
+                } else {
+                    (Self::MIN_VALUE / 4.0..=Self::MAX_VALUE / 4.0, 4.0)
+                }
+            }
+        }
+
+        #[cfg(feature = "rand08")]
+        impl<const MIN: $bits, const MAX: $bits>
+            rand08::distributions::Distribution<$type<MIN, MAX>> for rand08::distributions::Standard
+        {
+            #[inline]
+            fn sample<R: rand08::Rng + ?Sized>(&self, rng: &mut R) -> $type<MIN, MAX> {
+                let (range, scale) = $type::<MIN, MAX>::sample_range();
+                // Saturating handles a subnormal bound that is not exact after scaling.
+                $type::new_saturating(rng.gen_range(range) * scale)
+            }
+        }
+
+        #[cfg(feature = "rand09")]
+        impl<const MIN: $bits, const MAX: $bits> rand09::distr::Distribution<$type<MIN, MAX>>
+            for rand09::distr::StandardUniform
+        {
+            #[inline]
+            fn sample<R: rand09::Rng + ?Sized>(&self, rng: &mut R) -> $type<MIN, MAX> {
+                let (range, scale) = $type::<MIN, MAX>::sample_range();
+                // Saturating handles a subnormal bound that is not exact after scaling.
+                $type::new_saturating(rng.random_range(range) * scale)
+            }
+        }
+
+        #[cfg(feature = "rand010")]
+        impl<const MIN: $bits, const MAX: $bits> rand010::distr::Distribution<$type<MIN, MAX>>
+            for rand010::distr::StandardUniform
+        {
+            #[inline]
+            fn sample<R: rand010::Rng + ?Sized>(&self, rng: &mut R) -> $type<MIN, MAX> {
+                use rand010::RngExt as _;
+                let (range, scale) = $type::<MIN, MAX>::sample_range();
+                // Saturating handles a subnormal bound that is not exact after scaling.
+                $type::new_saturating(rng.random_range(range) * scale)
+            }
+        }
+
+        #[cfg(feature = "quickcheck")]
+        impl<const MIN: $bits, const MAX: $bits> quickcheck::Arbitrary for $type<MIN, MAX> {
+            #[inline]
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                // Interpolating between the bounds cannot overflow, unlike adding a multiple of
+                // the width of the range to the minimum.
+                let t = $internal::from(u16::arbitrary(g)) / $internal::from(u16::MAX);
+                Self::new_saturating(Self::MIN_VALUE * (1.0 - t) + Self::MAX_VALUE * t)
+            }
+
+            #[inline]
+            fn shrink(&self) -> ::alloc::boxed::Box<dyn Iterator<Item = Self>> {
+                ::alloc::boxed::Box::new(self.get().shrink().filter_map(Self::new))
+            }
+        }
+
+        #[cfg(feature = "num")]
+        impl<const MIN: $bits, const MAX: $bits> num_traits::Bounded for $type<MIN, MAX> {
+            #[inline(always)]
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            #[inline(always)]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+    )*};
+}
+
+impl_ranged_float! {
+    RangedF32 {
+        internal: f32
+        bits: u32
+    }
+    RangedF64 {
+        internal: f64
+        bits: u64
+    }
+}
@@ -0,0 +1,123 @@
+//! Indexing of arrays and slices by ranged integers.
+
+use core::ops::{Index, IndexMut};
+
+use crate::RangedUsize;
+
+/// Indexes an array without a runtime bounds check.
+///
+/// The range of the index is checked against the length of the array at compile time, so indexing
+/// can never be out of bounds.
+///
+/// # Example
+///
+/// ```rust
+/// # use deranged::RangedUsize;
+/// let table = [2, 3, 5, 7, 11];
+/// let index = RangedUsize::<0, 4>::new_static::<3>();
+/// assert_eq!(table[index], 7);
+/// ```
+///
+/// Using an index whose range is not entirely within the array fails to compile.
+///
+/// ```rust,compile_fail
+/// # use deranged::RangedUsize;
+/// let table = [2, 3, 5, 7, 11];
+/// let index = RangedUsize::<0, 5>::new_static::<3>();
+/// let _ = table[index];
+/// ```
+impl<T, const N: usize, const MIN: usize, const MAX: usize> Index<RangedUsize<MIN, MAX>>
+    for [T; N]
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: RangedUsize<MIN, MAX>) -> &Self::Output {
+        static_assert!(MIN <= MAX);
+        static_assert!(
+            MAX < N,
+            "the range of the index exceeds the length of the array"
+        );
+        // Safety: `index <= MAX < N`, so the index is in bounds.
+        unsafe { self.get_unchecked(index.get()) }
+    }
+}
+
+impl<T, const N: usize, const MIN: usize, const MAX: usize> IndexMut<RangedUsize<MIN, MAX>>
+    for [T; N]
+{
+    #[inline]
+    fn index_mut(&mut self, index: RangedUsize<MIN, MAX>) -> &mut Self::Output {
+        static_assert!(MIN <= MAX);
+        static_assert!(
+            MAX < N,
+            "the range of the index exceeds the length of the array"
+        );
+        // Safety: `index <= MAX < N`, so the index is in bounds.
+        unsafe { self.get_unchecked_mut(index.get()) }
+    }
+}
+
+/// Prevents downstream crates from implementing [`RangedSliceExt`].
+mod sealed {
+    /// A slice.
+    #[expect(unnameable_types, reason = "sealed trait")]
+    pub trait Sealed {}
+
+    impl<T> Sealed for [T] {}
+}
+
+/// Indexing of slices by ranged integers.
+///
+/// The length of a slice is only known at runtime, so unlike indexing an array, these methods check
+/// the index against it and return `None` if it is out of bounds. This trait is sealed and cannot
+/// be implemented outside of this crate.
+///
+/// # Example
+///
+/// ```rust
+/// # use deranged::{RangedSliceExt, RangedUsize};
+/// let table: &[u8] = &[2, 3, 5, 7, 11];
+/// assert_eq!(
+///     table.get_ranged(RangedUsize::<0, 7>::new_static::<3>()),
+///     Some(&7)
+/// );
+/// assert_eq!(
+///     table.get_ranged(RangedUsize::<0, 7>::new_static::<6>()),
+///     None
+/// );
+/// ```
+pub trait RangedSliceExt<T>: sealed::Sealed {
+    /// Returns a reference to the element at the given index, or `None` if it is out of bounds.
+    fn get_ranged<const MIN: usize, const MAX: usize>(
+        &self,
+        index: RangedUsize<MIN, MAX>,
+    ) -> Option<&T>;
+
+    /// Returns a mutable reference to the element at the given index, or `None` if it is out of
+    /// bounds.
+    fn get_ranged_mut<const MIN: usize, const MAX: usize>(
+        &mut self,
+        index: RangedUsize<MIN, MAX>,
+    ) -> Option<&mut T>;
+}
+
+impl<T> RangedSliceExt<T> for [T] {
+    #[inline]
+    fn get_ranged<const MIN: usize, const MAX: usize>(
+        &self,
+        index: RangedUsize<MIN, MAX>,
+    ) -> Option<&T> {
+        static_assert!(MIN <= MAX);
+        self.get(index.get())
+    }
+
+    #[inline]
+    fn get_ranged_mut<const MIN: usize, const MAX: usize>(
+        &mut self,
+        index: RangedUsize<MIN, MAX>,
+    ) -> Option<&mut T> {
+        static_assert!(MIN <= MAX);
+        self.get_mut(index.get())
+    }
+}
@@ -1,14 +1,67 @@
 //! `deranged` is a proof-of-concept implementation of ranged integers.
+//!
+//! # Inferred output ranges
+//!
+//! With the nightly-only `unstable-generic-const-exprs` feature enabled, the `+`, `-`, `*`, and `%`
+//! operators between two ranged integers of the same primitive type infer the range of the result
+//! from the ranges of the operands, rather than returning the type of the left-hand side. Adding a
+//! `RangedI16<A, B>` and a `RangedI16<C, D>` results in a `RangedI16<{ A + C }, { B + D }>`.
+//! Negation and `abs` infer the range of their result in the same manner. Compilation fails if a
+//! bound of the result cannot be represented by the primitive type or if the divisor of `%` may be
+//! zero. Crates using these operators must also enable `#![feature(generic_const_exprs)]`.
+//!
+//! **This feature is not additive.** It changes the output type of these operators and the return
+//! type of `abs`, and it removes the implementations of the operators that return the type of the
+//! left-hand side. As Cargo enables a feature for every crate in the dependency graph when any one
+//! crate enables it, other crates using these operators may fail to compile. The feature should
+//! only be enabled by a binary whose dependencies do not use these operators.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(
+    feature = "unstable-generic-const-exprs",
+    feature(generic_const_exprs),
+    expect(incomplete_features)
+)]
+#![cfg_attr(feature = "unstable-step-trait", feature(step_trait))]
 #![no_std]
 #![doc(test(attr(deny(warnings))))]
 
 #[cfg(all(feature = "alloc", any(feature = "serde", feature = "quickcheck")))]
 extern crate alloc;
+// Permits macros that refer to `::deranged` to be tested within this crate.
+#[cfg(all(test, feature = "macros"))]
+extern crate self as deranged;
 
+/// Assert that a condition holds, **failing to compile** if it does not.
+///
+/// This expands to a `const` block containing a single function call. When the
+/// `unstable-generic-const-exprs` feature is enabled, this is the only form of `const` block that
+/// may depend on generic parameters, so it must be used in place of `const { assert!(...) }`.
+macro_rules! static_assert {
+    ($condition:expr $(,)?) => {
+        static_assert!(
+            $condition,
+            concat!("assertion failed: ", stringify!($condition))
+        )
+    };
+    ($condition:expr, $message:expr $(,)?) => {
+        const { $crate::assert_condition($condition, $message) }
+    };
+}
+
+mod all_values;
+mod bounds;
+mod char;
+mod float;
+mod index;
+pub mod map;
+mod modular;
+mod newtype;
+mod saturating;
+pub mod set;
 #[cfg(test)]
 mod tests;
+mod traits;
 mod unsafe_wrapper;
 
 use core::borrow::Borrow;
@@ -16,7 +69,12 @@ use core::cmp::Ordering;
 use core::error::Error;
 use core::fmt;
 use core::hint::assert_unchecked;
+use core::iter::{FusedIterator, Sum};
 use core::num::{IntErrorKind, NonZero};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr,
+    ShrAssign, Sub, SubAssign,
+};
 use core::str::FromStr;
 
 /// A macro to define a ranged integer with an automatically computed inner type.
@@ -25,21 +83,53 @@ use core::str::FromStr;
 /// appropriate inner type to represent the range. This will be the smallest integer type that can
 /// store both the minimum and maximum values, with a preference for unsigned types if both are
 /// possible. To specifically request a signed or unsigned type, you can append a `i` or `u` suffix
-/// to either or both of the minimum and maximum values, respectively.
+/// to either or both of the minimum and maximum values, respectively. To request an exact inner
+/// type, including `usize` and `isize`, append its name as a suffix instead. A compile error is
+/// emitted if a value cannot be represented by the requested type.
+///
+/// The bounds may be separated by a comma or written as a range. An exclusive range, such as
+/// `0..100`, has a maximum value one less than its upper bound. A range without a lower bound, such
+/// as `..=100`, has a minimum value of zero, which takes the type of the upper bound: `..=5i` is
+/// signed and `..=5u16` is a `u16`. A compile error is emitted if the range is empty.
 ///
 /// # Examples
 ///
 /// ```rust,ignore
-/// int!(0, 100);  // RangedU8<0, 100>
-/// int!(0i, 100); // RangedI8<0, 100>
-/// int!(-5, 5);   // RangedI8<-5, 5>
-/// int!(-5u, 5);  // compile error (-5 cannot be unsigned)
+/// int!(0, 100);   // RangedU8<0, 100>
+/// int!(0i, 100);  // RangedI8<0, 100>
+/// int!(-5, 5);    // RangedI8<-5, 5>
+/// int!(-5u, 5);   // compile error (-5 cannot be unsigned)
+/// int!(0..=100);  // RangedU8<0, 100>
+/// int!(0..256);   // RangedU8<0, 255>
+/// int!(..=1000);  // RangedU16<0, 1000>
+/// int!(..=5i);    // RangedI8<0, 5>
+/// int!(10, 5);    // compile error (range is empty)
+/// int!(5..5);     // compile error (range is empty)
+/// int!(0, 100u32); // RangedU32<0, 100>
+/// int!(0usize, 9); // RangedUsize<0, 9>
+/// int!(0, 300u8);  // compile error (300 cannot be represented by `u8`)
+/// ```
+///
+/// The inner type may also be given before the bounds, followed by a semicolon. This is required
+/// for bounds that are named constants or other const expressions, which are checked when the type
+/// is used rather than by the macro.
+///
+/// ```rust,ignore
+/// const BUF_LEN: u16 = 64;
+/// const MAX: i8 = 5;
+/// int!(u16; 0, BUF_LEN - 1); // RangedU16<0, { BUF_LEN - 1 }>
+/// int!(u16; 0..BUF_LEN);     // RangedU16<0, { (BUF_LEN) - 1 }>
+/// int!(i8; -MAX, MAX);       // RangedI8<{ -MAX }, { MAX }>
+/// int!(u32; 0, 100);         // RangedU32<0, 100>
+/// int!(u32; 0u, 100u32);     // RangedU32<0, 100>
+/// int!(u32; 0i, 100);        // compile error (`i` conflicts with `u32`)
+/// int!(u32; 0u8, 100);       // compile error (`u8` conflicts with `u32`)
 /// ```
 #[cfg(docsrs)]
 #[doc(cfg(feature = "macros"))]
 #[macro_export]
 macro_rules! int {
-    ($min:literal, $max:literal) => {};
+    ($($tokens:tt)*) => {};
 }
 
 /// A macro to define an optional ranged integer with an automatically computed inner type.
@@ -48,40 +138,294 @@ macro_rules! int {
 /// appropriate inner type to represent the range. This will be the smallest integer type that can
 /// store both the minimum and maximum values, with a preference for unsigned types if both are
 /// possible. To specifically request a signed or unsigned type, you can append a `i` or `u` suffix
-/// to either or both of the minimum and maximum values, respectively.
+/// to either or both of the minimum and maximum values, respectively. To request an exact inner
+/// type, including `usize` and `isize`, append its name as a suffix instead. A compile error is
+/// emitted if a value cannot be represented by the requested type.
+///
+/// The bounds may be separated by a comma or written as a range. An exclusive range, such as
+/// `0..100`, has a maximum value one less than its upper bound. A range without a lower bound, such
+/// as `..=100`, has a minimum value of zero, which takes the type of the upper bound: `..=5i` is
+/// signed and `..=5u16` is a `u16`. A compile error is emitted if the range is empty.
 ///
 /// # Examples
 ///
 /// ```rust,ignore
-/// opt_int!(0, 100);  // OptionRangedU8<0, 100>
-/// opt_int!(0i, 100); // OptionRangedI8<0, 100>
-/// opt_int!(-5, 5);   // OptionRangedI8<-5, 5>
-/// opt_int!(-5u, 5);  // compile error (-5 cannot be unsigned)
+/// opt_int!(0, 100);   // OptionRangedU8<0, 100>
+/// opt_int!(0i, 100);  // OptionRangedI8<0, 100>
+/// opt_int!(-5, 5);    // OptionRangedI8<-5, 5>
+/// opt_int!(-5u, 5);   // compile error (-5 cannot be unsigned)
+/// opt_int!(0..=100);  // OptionRangedU8<0, 100>
+/// opt_int!(0..256);   // OptionRangedU8<0, 255>
+/// opt_int!(..=1000);  // OptionRangedU16<0, 1000>
+/// opt_int!(..=5i);    // OptionRangedI8<0, 5>
+/// opt_int!(10, 5);    // compile error (range is empty)
+/// opt_int!(5..5);     // compile error (range is empty)
+/// opt_int!(0, 100u32); // OptionRangedU32<0, 100>
+/// opt_int!(0usize, 9); // OptionRangedUsize<0, 9>
+/// opt_int!(0, 300u8);  // compile error (300 cannot be represented by `u8`)
+/// ```
+///
+/// The inner type may also be given before the bounds, followed by a semicolon. This is required
+/// for bounds that are named constants or other const expressions, which are checked when the type
+/// is used rather than by the macro.
+///
+/// ```rust,ignore
+/// const BUF_LEN: u16 = 64;
+/// const MAX: i8 = 5;
+/// opt_int!(u16; 0, BUF_LEN - 1); // OptionRangedU16<0, { BUF_LEN - 1 }>
+/// opt_int!(u16; 0..BUF_LEN);     // OptionRangedU16<0, { (BUF_LEN) - 1 }>
+/// opt_int!(i8; -MAX, MAX);       // OptionRangedI8<{ -MAX }, { MAX }>
+/// opt_int!(u32; 0, 100);         // OptionRangedU32<0, 100>
+/// opt_int!(u32; 0u, 100u32);     // OptionRangedU32<0, 100>
+/// opt_int!(u32; 0i, 100);        // compile error (`i` conflicts with `u32`)
+/// opt_int!(u32; 0u8, 100);       // compile error (`u8` conflicts with `u32`)
 /// ```
 #[cfg(docsrs)]
 #[doc(cfg(feature = "macros"))]
 #[macro_export]
 macro_rules! opt_int {
-    ($min:literal, $max:literal) => {};
+    ($($tokens:tt)*) => {};
 }
 
 #[cfg(all(not(docsrs), feature = "macros"))]
 pub use deranged_macros::int;
 #[cfg(all(not(docsrs), feature = "macros"))]
 pub use deranged_macros::opt_int;
+/// A macro to construct a ranged integer from a literal, checking its value at compile time.
+///
+/// The value is followed by `in` and the bounds, which are provided in any form accepted by
+/// [`int!`]. The type of the ranged integer is computed in the same manner. The value cannot
+/// have a suffix. When the bounds are literals, a compile error pointing at the value is
+/// emitted if it is not in the range. The macro expands to a call to `new_static`, so it can
+/// be used in const contexts, and bounds that are const expressions are checked by
+/// `new_static` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use deranged::{val, RangedI8, RangedU16, RangedU8, RangedUsize};
+/// const BUF_LEN: u16 = 64;
+///
+/// assert_eq!(val!(42 in 0..=100), RangedU8::<0, 100>::new_static::<42>());
+/// assert_eq!(val!(-3 in -5..5), RangedI8::<-5, 4>::new_static::<-3>());
+/// assert_eq!(val!(-7 in -10..-5), RangedI8::<-10, -6>::new_static::<-7>());
+/// assert_eq!(val!(7 in 0, 10usize), RangedUsize::<0, 10>::new_static::<7>());
+/// assert_eq!(val!(7 in u16; 0..BUF_LEN), RangedU16::<0, 63>::new_static::<7>());
+/// ```
+///
+/// A value that is not in the range is rejected.
+///
+/// ```rust,compile_fail
+/// # use deranged::val;
+/// let _ = val!(101 in 0..=100);
+/// ```
+///
+/// As with [`int!`], a suffix on a bound must agree with an explicit type.
+///
+/// ```rust,compile_fail
+/// # use deranged::val;
+/// let _ = val!(3 in u32; 0i, 5);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use deranged::val;
+/// let _ = val!(3 in u32; 0u8, 5);
+/// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use deranged_macros::val;
+/// Derive conversions between a fieldless enum and a ranged integer.
+///
+/// The ranged integer spans the smallest to the largest discriminant of the enum, with the
+/// inner type chosen in the same manner as [`int!`]. Every enum converts to its ranged integer
+/// with `From`. When the discriminants are contiguous, the ranged integer converts back to the
+/// enum with `From`. Otherwise it converts back with `TryFrom`, returning the ranged integer
+/// as the error when no variant has that discriminant.
+///
+/// If the enum has a `#[repr(..)]` attribute with a primitive integer type, the ranged integer
+/// uses that type as well. Otherwise the inner type is the smallest that can store every
+/// discriminant.
+///
+/// Discriminants must be integer literals or omitted.
+///
+/// # Examples
+///
+/// ```rust
+/// # use deranged::{RangedRepr, RangedU8};
+/// #[derive(Debug, PartialEq, RangedRepr)]
+/// enum Weekday {
+///     Monday = 1,
+///     Tuesday,
+///     Wednesday,
+///     Thursday,
+///     Friday,
+///     Saturday,
+///     Sunday,
+/// }
+///
+/// let day = RangedU8::<1, 7>::from(Weekday::Wednesday);
+/// assert_eq!(day.get(), 3);
+/// assert_eq!(Weekday::from(RangedU8::<1, 7>::MAX), Weekday::Sunday);
+/// ```
+///
+/// ```rust
+/// # use deranged::{RangedI8, RangedRepr};
+/// #[derive(Debug, PartialEq, RangedRepr)]
+/// enum Level {
+///     Low = -5,
+///     Medium = 0,
+///     High = 5,
+/// }
+///
+/// assert_eq!(RangedI8::<-5, 5>::from(Level::Medium).get(), 0);
+/// assert_eq!(Level::try_from(RangedI8::<-5, 5>::MAX), Ok(Level::High));
+/// assert_eq!(
+///     Level::try_from(RangedI8::<-5, 5>::new_static::<1>()),
+///     Err(RangedI8::<-5, 5>::new_static::<1>())
+/// );
+/// ```
+///
+/// ```rust
+/// # use deranged::{RangedRepr, RangedU16};
+/// #[derive(RangedRepr)]
+/// #[repr(u16)]
+/// enum Status {
+///     Ok = 200,
+///     NotFound = 404,
+/// }
+///
+/// assert_eq!(RangedU16::<200, 404>::from(Status::NotFound).get(), 404);
+/// ```
+///
+/// The derive is rejected for anything other than an enum,
+///
+/// ```rust,compile_fail
+/// # use deranged::RangedRepr;
+/// #[derive(RangedRepr)]
+/// struct Weekday(u8);
+/// ```
+///
+/// for enums with fields,
+///
+/// ```rust,compile_fail
+/// # use deranged::RangedRepr;
+/// #[derive(RangedRepr)]
+/// enum Shape {
+///     Circle(f32),
+///     Square { side: f32 },
+/// }
+/// ```
+///
+/// for generic enums,
+///
+/// ```rust,compile_fail
+/// # use deranged::RangedRepr;
+/// #[derive(RangedRepr)]
+/// enum Either<L, R> {
+///     Left,
+///     Right,
+///     #[allow(dead_code)]
+///     Never(core::marker::PhantomData<(L, R)>),
+/// }
+/// ```
+///
+/// for discriminants that are not integer literals,
+///
+/// ```rust,compile_fail
+/// # use deranged::RangedRepr;
+/// const BASE: isize = 10;
+///
+/// #[derive(RangedRepr)]
+/// enum Level {
+///     Low = BASE,
+///     High = BASE + 1,
+/// }
+/// ```
+///
+/// and for enums without variants.
+///
+/// ```rust,compile_fail
+/// # use deranged::RangedRepr;
+/// #[derive(RangedRepr)]
+/// enum Never {}
+/// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use deranged_macros::RangedRepr;
 #[cfg(feature = "powerfmt")]
 use powerfmt::smart_display;
 
+pub use crate::all_values::AllValues;
+pub use crate::char::{ParseCharError, RangedChar};
+pub use crate::float::{RangedF32, RangedF64};
+pub use crate::index::RangedSliceExt;
+pub use crate::map::RangedMap;
+pub use crate::modular::Modular;
+#[doc(hidden)]
+pub use crate::newtype::__private;
+pub use crate::newtype::NewtypeError;
+pub use crate::saturating::Saturating;
+pub use crate::set::RangedSet;
+use crate::traits::{Dense, Sealed};
+pub use crate::traits::{OptionalRanged, Ranged};
 use crate::unsafe_wrapper::Unsafe;
 
 /// The error type returned when a checked integral type conversion fails.
+///
+/// # Example
+///
+/// ```rust
+/// # use deranged::RangedU8;
+/// let err = RangedU8::<1, 12>::try_from(13).unwrap_err();
+/// assert_eq!(err.value::<u8>(), Some(13));
+/// assert_eq!(err.to_string(), "13 is not in the range 1..=12");
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct TryFromIntError;
+pub struct TryFromIntError {
+    /// Whether the value is above or below the range.
+    kind: RangeErrorKind,
+    /// The value that was not in range.
+    value: ErrorInt,
+    /// The minimum value of the range.
+    min: ErrorInt,
+    /// The maximum value of the range.
+    max: ErrorInt,
+}
+
+impl TryFromIntError {
+    /// Whether the value was above or below the range.
+    #[inline]
+    pub const fn kind(&self) -> RangeErrorKind {
+        self.kind
+    }
+
+    /// The value that was not in range, if it is representable by `T`.
+    #[inline]
+    pub fn value<T: TryFrom<i128> + TryFrom<u128>>(&self) -> Option<T> {
+        self.value.to()
+    }
+
+    /// The minimum value of the range, if it is representable by `T`.
+    #[inline]
+    pub fn min<T: TryFrom<i128> + TryFrom<u128>>(&self) -> Option<T> {
+        self.min.to()
+    }
+
+    /// The maximum value of the range, if it is representable by `T`.
+    #[inline]
+    pub fn max<T: TryFrom<i128> + TryFrom<u128>>(&self) -> Option<T> {
+        self.max.to()
+    }
+}
 
 impl fmt::Display for TryFromIntError {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("out of range integral type conversion attempted")
+        write!(
+            f,
+            "{} is not in the range {}..={}",
+            self.value, self.min, self.max
+        )
     }
 }
 impl Error for TryFromIntError {}
@@ -110,6 +454,12 @@ impl Error for TryFromIntError {}
 pub struct ParseIntError {
     #[allow(clippy::missing_docs_in_private_items)]
     kind: IntErrorKind,
+    /// The parsed value that was not in range, if it is representable by a 128-bit integer.
+    value: Option<ErrorInt>,
+    /// The minimum value of the range.
+    min: ErrorInt,
+    /// The maximum value of the range.
+    max: ErrorInt,
 }
 
 impl ParseIntError {
@@ -120,25 +470,209 @@ impl ParseIntError {
     pub fn kind(&self) -> &IntErrorKind {
         &self.kind
     }
+
+    /// The parsed value that was not in range.
+    ///
+    /// Returns `None` if the string was not a valid integer, if the value is not representable
+    /// by a 128-bit integer or if it is not representable by `T`.
+    #[inline]
+    pub fn value<T: TryFrom<i128> + TryFrom<u128>>(&self) -> Option<T> {
+        self.value?.to()
+    }
+
+    /// The minimum value of the range, if it is representable by `T`.
+    #[inline]
+    pub fn min<T: TryFrom<i128> + TryFrom<u128>>(&self) -> Option<T> {
+        self.min.to()
+    }
+
+    /// The maximum value of the range, if it is representable by `T`.
+    #[inline]
+    pub fn max<T: TryFrom<i128> + TryFrom<u128>>(&self) -> Option<T> {
+        self.max.to()
+    }
 }
 
 impl fmt::Display for ParseIntError {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.kind {
-            IntErrorKind::Empty => "cannot parse integer from empty string",
-            IntErrorKind::InvalidDigit => "invalid digit found in string",
-            IntErrorKind::PosOverflow => "number too large to fit in target type",
-            IntErrorKind::NegOverflow => "number too small to fit in target type",
-            IntErrorKind::Zero => "number would be zero for non-zero type",
-            _ => "Unknown Int error kind",
-        }
-        .fmt(f)
+        let Self {
+            kind,
+            value,
+            min,
+            max,
+        } = self;
+        match (kind, value) {
+            (IntErrorKind::PosOverflow | IntErrorKind::NegOverflow, Some(value)) => {
+                write!(f, "{value} is not in the range {min}..={max}")
+            }
+            (IntErrorKind::PosOverflow, None) => {
+                write!(f, "number too large to fit in the range {min}..={max}")
+            }
+            (IntErrorKind::NegOverflow, None) => {
+                write!(f, "number too small to fit in the range {min}..={max}")
+            }
+            (IntErrorKind::Empty, _) => f.write_str("cannot parse integer from empty string"),
+            (IntErrorKind::InvalidDigit, _) => f.write_str("invalid digit found in string"),
+            (IntErrorKind::Zero, _) => f.write_str("number would be zero for non-zero type"),
+            _ => f.write_str("Unknown Int error kind"),
+        }
     }
 }
 
 impl Error for ParseIntError {}
 
+/// A primitive integer of any width, used to store values in errors.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ErrorInt {
+    /// A value of a signed integer type.
+    Signed(i128),
+    /// A value of an unsigned integer type.
+    Unsigned(u128),
+}
+
+impl ErrorInt {
+    /// Parse a string as the widest integer type of the given signedness, returning `None` if it
+    /// cannot be represented.
+    #[inline]
+    fn from_str_radix(src: &str, radix: u32, signed: bool) -> Option<Self> {
+        if signed {
+            i128::from_str_radix(src, radix).ok().map(Self::Signed)
+        } else {
+            u128::from_str_radix(src, radix).ok().map(Self::Unsigned)
+        }
+    }
+
+    /// Convert the value to the requested integer type, returning `None` if it is not
+    /// representable.
+    #[inline]
+    fn to<T: TryFrom<i128> + TryFrom<u128>>(self) -> Option<T> {
+        match self {
+            Self::Signed(value) => T::try_from(value).ok(),
+            Self::Unsigned(value) => T::try_from(value).ok(),
+        }
+    }
+}
+
+impl fmt::Debug for ErrorInt {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for ErrorInt {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed(value) => value.fmt(f),
+            Self::Unsigned(value) => value.fmt(f),
+        }
+    }
+}
+
+/// The reason that a fallible arithmetic operation on a ranged integer failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeErrorKind {
+    /// The result is greater than the maximum value of the range.
+    AboveMax,
+    /// The result is less than the minimum value of the range.
+    BelowMin,
+    /// The divisor is zero.
+    DivisionByZero,
+}
+
+/// The error type returned when the result of a fallible arithmetic operation is not in range.
+///
+/// This error is returned by the `try_*` methods on ranged integer types, such as
+/// [`RangedU8::try_add`].
+///
+/// # Example
+///
+/// ```rust
+/// # use deranged::{RangeErrorKind, RangedU8};
+/// let err = RangedU8::<1, 12>::new_static::<10>()
+///     .try_add(3)
+///     .unwrap_err();
+/// assert_eq!(err.kind(), RangeErrorKind::AboveMax);
+/// assert_eq!(err.value::<u8>(), Some(13));
+/// assert_eq!(err.to_string(), "13 is not in the range 1..=12");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeError {
+    /// Whether the result is above or below the range.
+    kind: RangeErrorKind,
+    /// The result of the operation, if it is representable by the primitive type.
+    value: Option<ErrorInt>,
+    /// The minimum value of the range.
+    min: ErrorInt,
+    /// The maximum value of the range.
+    max: ErrorInt,
+}
+
+impl RangeError {
+    /// The reason that the operation failed.
+    #[inline]
+    pub const fn kind(&self) -> RangeErrorKind {
+        self.kind
+    }
+
+    /// The result of the operation that was not in range.
+    ///
+    /// Returns `None` if the result is not representable by the primitive type of the ranged
+    /// integer or by `T`, or if there is no result (as with division by zero).
+    #[inline]
+    pub fn value<T: TryFrom<i128> + TryFrom<u128>>(&self) -> Option<T> {
+        self.value?.to()
+    }
+
+    /// The minimum value of the range, if it is representable by `T`.
+    #[inline]
+    pub fn min<T: TryFrom<i128> + TryFrom<u128>>(&self) -> Option<T> {
+        self.min.to()
+    }
+
+    /// The maximum value of the range, if it is representable by `T`.
+    #[inline]
+    pub fn max<T: TryFrom<i128> + TryFrom<u128>>(&self) -> Option<T> {
+        self.max.to()
+    }
+}
+
+impl fmt::Display for RangeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            kind,
+            value,
+            min,
+            max,
+        } = self;
+        match (kind, value) {
+            (RangeErrorKind::DivisionByZero, _) => f.write_str("attempt to divide by zero"),
+            (_, Some(value)) => write!(f, "{value} is not in the range {min}..={max}"),
+            (RangeErrorKind::AboveMax, None) => {
+                write!(f, "result is too large to fit in the range {min}..={max}")
+            }
+            (RangeErrorKind::BelowMin, None) => {
+                write!(f, "result is too small to fit in the range {min}..={max}")
+            }
+        }
+    }
+}
+
+impl Error for RangeError {}
+
+/// Panic with the provided message if the condition does not hold. Used by `static_assert!`.
+#[track_caller]
+#[inline(always)]
+const fn assert_condition(condition: bool, message: &str) {
+    if !condition {
+        panic!("{}", message);
+    }
+}
+
 /// `?` for `Option` types, usable in `const` contexts.
 macro_rules! const_try_opt {
     ($e:expr) => {
@@ -195,6 +729,228 @@ macro_rules! if_not_manual_rand_010 {
     };
 }
 
+/// Implement a binary operator and its assigning counterpart for a ranged integer type.
+///
+/// The `ranged` form additionally implements the operator with any ranged integer of the same
+/// primitive type as the right-hand side. Attributes provided before the type are applied to the
+/// non-assigning implementation of this form.
+macro_rules! impl_binary_op {
+    (
+        ranged
+        $(#[$attr:meta])*
+        $type:ident
+        $internal:ident :
+        $trait:ident
+        $method:ident
+        $assign_trait:ident
+        $assign_method:ident
+        $checked:ident
+        $message:literal
+    ) => {
+        impl_binary_op! {
+            $type $internal $internal:
+            $trait $method $assign_trait $assign_method $checked $message
+        }
+
+        $(#[$attr])*
+        impl<
+                const MIN: $internal,
+                const MAX: $internal,
+                const RHS_MIN: $internal,
+                const RHS_MAX: $internal,
+            > $trait<$type<RHS_MIN, RHS_MAX>> for $type<MIN, MAX>
+        {
+            type Output = Self;
+
+            #[track_caller]
+            #[inline]
+            fn $method(self, rhs: $type<RHS_MIN, RHS_MAX>) -> Self {
+                static_assert!(MIN <= MAX);
+                static_assert!(RHS_MIN <= RHS_MAX);
+                $trait::$method(self, rhs.get())
+            }
+        }
+
+        impl<
+                const MIN: $internal,
+                const MAX: $internal,
+                const RHS_MIN: $internal,
+                const RHS_MAX: $internal,
+            > $assign_trait<$type<RHS_MIN, RHS_MAX>> for $type<MIN, MAX>
+        {
+            #[track_caller]
+            #[inline]
+            fn $assign_method(&mut self, rhs: $type<RHS_MIN, RHS_MAX>) {
+                static_assert!(MIN <= MAX);
+                static_assert!(RHS_MIN <= RHS_MAX);
+                *self = $trait::$method(*self, rhs.get());
+            }
+        }
+    };
+    (
+        $type:ident
+        $internal:ident
+        $rhs:ty :
+        $trait:ident
+        $method:ident
+        $assign_trait:ident
+        $assign_method:ident
+        $checked:ident
+        $message:literal
+    ) => {
+        impl<const MIN: $internal, const MAX: $internal> $trait<$rhs> for $type<MIN, MAX> {
+            type Output = Self;
+
+            #[track_caller]
+            #[inline]
+            fn $method(self, rhs: $rhs) -> Self {
+                static_assert!(MIN <= MAX);
+                match self.$checked(rhs) {
+                    Some(value) => value,
+                    None => panic!($message),
+                }
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> $assign_trait<$rhs> for $type<MIN, MAX> {
+            #[track_caller]
+            #[inline]
+            fn $assign_method(&mut self, rhs: $rhs) {
+                static_assert!(MIN <= MAX);
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+/// Implement a binary operator between two ranged integers of the same primitive type, with the
+/// range of the output inferred from the ranges of the operands. The operation is forwarded to the
+/// provided `*_ranged` method.
+macro_rules! impl_inferred_op {
+    (
+        $type:ident
+        $internal:ident :
+        $trait:ident
+        $method:ident
+        $forward:ident :
+        $out_min:expr,
+        $out_max:expr $(,)?
+    ) => {
+        #[cfg(feature = "unstable-generic-const-exprs")]
+        impl<
+                const MIN: $internal,
+                const MAX: $internal,
+                const RHS_MIN: $internal,
+                const RHS_MAX: $internal,
+            > $trait<$type<RHS_MIN, RHS_MAX>> for $type<MIN, MAX>
+        where
+            $type<{ $out_min }, { $out_max }>: Sized,
+        {
+            type Output = $type<{ $out_min }, { $out_max }>;
+
+            #[inline]
+            fn $method(self, rhs: $type<RHS_MIN, RHS_MAX>) -> Self::Output {
+                self.$forward(rhs)
+            }
+        }
+    };
+}
+
+/// Implement a binary operator and its assigning counterpart for a wrapper around a ranged
+/// integer type, forwarding to the provided method of the inner type.
+macro_rules! impl_wrapper_op {
+    (
+        $wrapper:ident
+        $type:ident
+        $internal:ident :
+        $trait:ident
+        $method:ident
+        $assign_trait:ident
+        $assign_method:ident
+        $forward:ident
+    ) => {
+        impl<const MIN: $internal, const MAX: $internal> $trait<$internal>
+            for $wrapper<$type<MIN, MAX>>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: $internal) -> Self {
+                static_assert!(MIN <= MAX);
+                $wrapper(self.0.$forward(rhs))
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> $trait for $wrapper<$type<MIN, MAX>> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                static_assert!(MIN <= MAX);
+                $wrapper(self.0.$forward(rhs.0.get()))
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> $assign_trait<$internal>
+            for $wrapper<$type<MIN, MAX>>
+        {
+            #[inline]
+            fn $assign_method(&mut self, rhs: $internal) {
+                static_assert!(MIN <= MAX);
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> $assign_trait
+            for $wrapper<$type<MIN, MAX>>
+        {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                static_assert!(MIN <= MAX);
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    };
+    (
+        $wrapper:ident
+        $type:ident
+        $internal:ident :
+        Sum
+        $empty:literal
+        |$iter:ident| $sum:expr
+    ) => {
+        impl<const MIN: $internal, const MAX: $internal> Sum for $wrapper<$type<MIN, MAX>> {
+            #[doc = concat!(
+                "Sums the values as if by repeatedly applying `+`, starting from zero. The sum of \
+                an empty iterator is ",
+                $empty,
+                ".",
+            )]
+            #[inline]
+            fn sum<I: Iterator<Item = Self>>($iter: I) -> Self {
+                static_assert!(MIN <= MAX);
+                $sum
+            }
+        }
+
+        impl<'a, const MIN: $internal, const MAX: $internal> Sum<&'a Self>
+            for $wrapper<$type<MIN, MAX>>
+        {
+            #[doc = concat!(
+                "Sums the values as if by repeatedly applying `+`, starting from zero. The sum of \
+                an empty iterator is ",
+                $empty,
+                ".",
+            )]
+            #[inline]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                static_assert!(MIN <= MAX);
+                iter.copied().sum()
+            }
+        }
+    };
+}
+
 /// Implement a ranged integer type.
 macro_rules! impl_ranged {
     ($(
@@ -207,6 +963,7 @@ macro_rules! impl_ranged {
             optional: $optional_type:ident
             optional_alias: $optional_alias:ident
             from: [$($from:ident($from_internal:ident))+]
+            $(widening: $wide:ident($wide_internal:ident))?
             $(manual: [$($skips:ident)+])?
         }
     )*) => {$(
@@ -294,6 +1051,24 @@ macro_rules! impl_ranged {
             // Safety: `MAX` is in range by definition.
             pub const MAX: Self = Self::new_static::<MAX>();
 
+            /// Returns an iterator over every value of this type, in ascending order.
+            ///
+            /// ```rust
+            #[doc = concat!("# use deranged::", stringify!($type), ";")]
+            #[doc = concat!("let mut values = ", stringify!($type), "::<1, 3>::all();")]
+            #[doc = concat!("assert_eq!(values.next(), Some(", stringify!($type), "::<1, 3>::MIN));")]
+            /// assert_eq!(values.len(), 2);
+            /// ```
+            #[inline]
+            pub const fn all() -> AllValues<Self> {
+                static_assert!(MIN <= MAX);
+                AllValues {
+                    front: Self::MIN,
+                    back: Self::MAX,
+                    exhausted: false,
+                }
+            }
+
             /// Creates a ranged integer without checking the value.
             ///
             /// # Safety
@@ -302,7 +1077,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn new_unchecked(value: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the value is in range.
                 unsafe {
                     assert_unchecked(MIN <= value && value <= MAX);
@@ -319,7 +1094,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const fn get(self) -> $internal {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: A stored value is always in range.
                 unsafe { assert_unchecked(MIN <= *self.0.get() && *self.0.get() <= MAX) };
                 *self.0.get()
@@ -333,14 +1108,14 @@ macro_rules! impl_ranged {
             /// optimization.
             #[inline(always)]
             pub const fn get_without_hint(self) -> $internal {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 *self.0.get()
             }
 
             #[track_caller]
             #[inline(always)]
             pub(crate) const fn get_ref(&self) -> &$internal {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 let value = self.0.get();
                 // Safety: A stored value is always in range.
                 unsafe { assert_unchecked(MIN <= *value && *value <= MAX) };
@@ -350,7 +1125,7 @@ macro_rules! impl_ranged {
             /// Creates a ranged integer if the given value is in the range `MIN..=MAX`.
             #[inline(always)]
             pub const fn new(value: $internal) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 if value < MIN || value > MAX {
                     None
                 } else {
@@ -363,10 +1138,8 @@ macro_rules! impl_ranged {
             /// value is not in range.
             #[inline(always)]
             pub const fn new_static<const VALUE: $internal>() -> Self {
-                const {
-                    assert!(MIN <= VALUE);
-                    assert!(VALUE <= MAX);
-                }
+                static_assert!(MIN <= VALUE);
+                static_assert!(VALUE <= MAX);
                 // Safety: The value is in range.
                 unsafe { Self::new_unchecked(VALUE) }
             }
@@ -374,7 +1147,7 @@ macro_rules! impl_ranged {
             /// Creates a ranged integer with the given value, saturating if it is out of range.
             #[inline]
             pub const fn new_saturating(value: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 if value < MIN {
                     Self::MIN
                 } else if value > MAX {
@@ -397,7 +1170,7 @@ macro_rules! impl_ranged {
             /// [`cargo-show-asm`]: https://crates.io/crates/cargo-show-asm
             #[inline(always)]
             pub const fn emit_range_hint(self) {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 let value = self.0.get();
                 // Safety: A stored value is always in range.
                 unsafe { assert_unchecked(MIN <= *value && *value <= MAX) };
@@ -409,12 +1182,10 @@ macro_rules! impl_ranged {
             pub const fn expand<const NEW_MIN: $internal, const NEW_MAX: $internal>(
                 self,
             ) -> $type<NEW_MIN, NEW_MAX> {
-                const {
-                    assert!(MIN <= MAX);
-                    assert!(NEW_MIN <= NEW_MAX);
-                    assert!(NEW_MIN <= MIN);
-                    assert!(NEW_MAX >= MAX);
-                }
+                static_assert!(MIN <= MAX);
+                static_assert!(NEW_MIN <= NEW_MAX);
+                static_assert!(NEW_MIN <= MIN);
+                static_assert!(NEW_MAX >= MAX);
                 // Safety: The range is widened.
                 unsafe { $type::new_unchecked(self.get()) }
             }
@@ -427,12 +1198,10 @@ macro_rules! impl_ranged {
                 const NEW_MIN: $internal,
                 const NEW_MAX: $internal,
             >(self) -> Option<$type<NEW_MIN, NEW_MAX>> {
-                const {
-                    assert!(MIN <= MAX);
-                    assert!(NEW_MIN <= NEW_MAX);
-                    assert!(NEW_MIN >= MIN);
-                    assert!(NEW_MAX <= MAX);
-                }
+                static_assert!(MIN <= MAX);
+                static_assert!(NEW_MIN <= NEW_MAX);
+                static_assert!(NEW_MIN >= MIN);
+                static_assert!(NEW_MAX <= MAX);
                 $type::<NEW_MIN, NEW_MAX>::new(self.get())
             }
 
@@ -447,12 +1216,10 @@ macro_rules! impl_ranged {
                 const NEW_MIN: $internal,
                 const NEW_MAX: $internal,
             >(self) -> $type<NEW_MIN, NEW_MAX> {
-                const {
-                    assert!(MIN <= MAX);
-                    assert!(NEW_MIN <= NEW_MAX);
-                    assert!(NEW_MIN >= MIN);
-                    assert!(NEW_MAX <= MAX);
-                }
+                static_assert!(MIN <= MAX);
+                static_assert!(NEW_MIN <= NEW_MAX);
+                static_assert!(NEW_MIN >= MIN);
+                static_assert!(NEW_MAX <= MAX);
                 // Safety: The caller must ensure that the value is in the new range.
                 unsafe { $type::new_unchecked(self.get()) }
             }
@@ -487,18 +1254,34 @@ macro_rules! impl_ranged {
             /// ```
             #[inline]
             pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
+                let parse_error = |kind, value| ParseIntError {
+                    kind,
+                    value,
+                    min: Self::to_error_int(MIN),
+                    max: Self::to_error_int(MAX),
+                };
                 match $internal::from_str_radix(src, radix) {
-                    Ok(value) if value > MAX => {
-                        Err(ParseIntError { kind: IntErrorKind::PosOverflow })
-                    }
-                    Ok(value) if value < MIN => {
-                        Err(ParseIntError { kind: IntErrorKind::NegOverflow })
-                    }
+                    Ok(value) if value > MAX => Err(parse_error(
+                        IntErrorKind::PosOverflow,
+                        Some(Self::to_error_int(value)),
+                    )),
+                    Ok(value) if value < MIN => Err(parse_error(
+                        IntErrorKind::NegOverflow,
+                        Some(Self::to_error_int(value)),
+                    )),
                     // Safety: If the value was out of range, it would have been caught in a
                     // previous arm.
                     Ok(value) => Ok(unsafe { Self::new_unchecked(value) }),
-                    Err(e) => Err(ParseIntError { kind: e.kind().clone() }),
+                    // The value may still be representable by a wider type.
+                    Err(e) if matches!(
+                        e.kind(),
+                        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow
+                    ) => Err(parse_error(
+                        e.kind().clone(),
+                        ErrorInt::from_str_radix(src, radix, $internal::MIN != 0),
+                    )),
+                    Err(e) => Err(parse_error(e.kind().clone(), None)),
                 }
             }
 
@@ -507,7 +1290,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_add(self, rhs: $internal) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_add(rhs)))
             }
 
@@ -521,7 +1304,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_add(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range.
                 unsafe {
                     Self::new_unchecked(self.get().unchecked_add(rhs))
@@ -533,7 +1316,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_sub(self, rhs: $internal) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_sub(rhs)))
             }
 
@@ -547,7 +1330,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_sub(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range.
                 unsafe {
                     Self::new_unchecked(self.get().unchecked_sub(rhs))
@@ -559,7 +1342,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_mul(self, rhs: $internal) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_mul(rhs)))
             }
 
@@ -573,7 +1356,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_mul(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range.
                 unsafe {
                     Self::new_unchecked(self.get().unchecked_mul(rhs))
@@ -585,7 +1368,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_div(self, rhs: $internal) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_div(rhs)))
             }
 
@@ -600,7 +1383,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_div(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range and that `rhs` is not
                 // zero.
                 unsafe {
@@ -613,7 +1396,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_div_euclid(self, rhs: $internal) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_div_euclid(rhs)))
             }
 
@@ -628,7 +1411,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_div_euclid(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range and that `rhs` is not
                 // zero.
                 unsafe {
@@ -648,7 +1431,7 @@ macro_rules! impl_ranged {
                 self,
                 rhs: $type<RHS_VALUE, RHS_VALUE>,
             ) -> $type<0, RHS_VALUE> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The result is guaranteed to be in range due to the nature of remainder on
                 // unsigned integers.
                 unsafe { $type::new_unchecked(self.get() % rhs.get()) }
@@ -659,7 +1442,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_rem(self, rhs: $internal) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_rem(rhs)))
             }
 
@@ -674,7 +1457,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_rem(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range and that `rhs` is not
                 // zero.
                 unsafe {
@@ -687,7 +1470,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_rem_euclid(self, rhs: $internal) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_rem_euclid(rhs)))
             }
 
@@ -702,7 +1485,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_rem_euclid(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range and that `rhs` is not
                 // zero.
                 unsafe {
@@ -717,7 +1500,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_neg(self) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_neg()))
             }
 
@@ -730,7 +1513,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_neg(self) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range.
                 // TODO(MSRV 1.93) use `unchecked_neg`
                 unsafe { Self::new_unchecked(self.get().checked_neg().unwrap_unchecked()) }
@@ -741,27 +1524,236 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline(always)]
             pub const fn neg(self) -> Self {
-                const {
-                    assert!(MIN <= MAX);
-                    if_signed! { $is_signed
-                        assert!(MIN != $internal::MIN);
-                        assert!(-MIN <= MAX);
-                        assert!(-MAX >= MIN);
-                    }
-                    if_unsigned! { $is_signed
-                        assert!(MAX == 0);
-                    }
+                static_assert!(MIN <= MAX);
+                if_signed! { $is_signed
+                    static_assert!(MIN != $internal::MIN);
+                    static_assert!(-MIN <= MAX);
+                    static_assert!(-MAX >= MIN);
+                }
+                if_unsigned! { $is_signed
+                    static_assert!(MAX == 0);
                 }
                 // Safety: The compiler asserts that the result is in range.
                 unsafe { self.unchecked_neg() }
             }
 
-            /// Checked shift left. Computes `self << rhs`, returning `None` if the resulting value
+            /// Addition with a statically known output range. Computes `self + rhs`, **failing to
+            /// compile** if the result is not guaranteed to be in the range `OUT_MIN..=OUT_MAX`.
+            ///
+            /// The output range is typically inferred from the context in which the result is
+            /// used.
+            ///
+            /// ```rust
+            #[doc = concat!("# use deranged::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 10>::new_static::<5>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<1, 3>::new_static::<2>();")]
+            #[doc = concat!("let sum: ", stringify!($type), "<1, 13> = a.add_ranged(b);")]
+            /// assert_eq!(sum.get(), 7);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn add_ranged<
+                const OUT_MIN: $internal,
+                const OUT_MAX: $internal,
+                const RHS_MIN: $internal,
+                const RHS_MAX: $internal,
+            >(self, rhs: $type<RHS_MIN, RHS_MAX>) -> $type<OUT_MIN, OUT_MAX> {
+                static_assert!(MIN <= MAX);
+                static_assert!(RHS_MIN <= RHS_MAX);
+                static_assert!(OUT_MIN <= MIN + RHS_MIN);
+                static_assert!(MAX + RHS_MAX <= OUT_MAX);
+                // Safety: The compiler asserts that the result is in range.
+                unsafe { $type::new_unchecked(self.get().unchecked_add(rhs.get())) }
+            }
+
+            /// Subtraction with a statically known output range. Computes `self - rhs`, **failing
+            /// to compile** if the result is not guaranteed to be in the range
+            /// `OUT_MIN..=OUT_MAX`.
+            ///
+            /// The output range is typically inferred from the context in which the result is
+            /// used.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn sub_ranged<
+                const OUT_MIN: $internal,
+                const OUT_MAX: $internal,
+                const RHS_MIN: $internal,
+                const RHS_MAX: $internal,
+            >(self, rhs: $type<RHS_MIN, RHS_MAX>) -> $type<OUT_MIN, OUT_MAX> {
+                static_assert!(MIN <= MAX);
+                static_assert!(RHS_MIN <= RHS_MAX);
+                static_assert!(OUT_MIN <= MIN - RHS_MAX);
+                static_assert!(MAX - RHS_MIN <= OUT_MAX);
+                // Safety: The compiler asserts that the result is in range.
+                unsafe { $type::new_unchecked(self.get().unchecked_sub(rhs.get())) }
+            }
+
+            /// Multiplication with a statically known output range. Computes `self * rhs`,
+            /// **failing to compile** if the result is not guaranteed to be in the range
+            /// `OUT_MIN..=OUT_MAX`.
+            ///
+            /// The output range is typically inferred from the context in which the result is
+            /// used.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn mul_ranged<
+                const OUT_MIN: $internal,
+                const OUT_MAX: $internal,
+                const RHS_MIN: $internal,
+                const RHS_MAX: $internal,
+            >(self, rhs: $type<RHS_MIN, RHS_MAX>) -> $type<OUT_MIN, OUT_MAX> {
+                static_assert!(MIN <= MAX);
+                static_assert!(RHS_MIN <= RHS_MAX);
+                static_assert!(OUT_MIN <= bounds::$internal::mul_min(MIN, MAX, RHS_MIN, RHS_MAX));
+                static_assert!(bounds::$internal::mul_max(MIN, MAX, RHS_MIN, RHS_MAX) <= OUT_MAX);
+                // Safety: The compiler asserts that the result is in range.
+                unsafe { $type::new_unchecked(self.get().unchecked_mul(rhs.get())) }
+            }
+
+            $(
+            /// Widening multiplication with a statically known output range. Computes
+            #[doc = concat!(
+                "`self * rhs` as [`",
+                stringify!($wide),
+                "`], **failing to compile** if the result is not guaranteed to be in the range",
+            )]
+            /// `OUT_MIN..=OUT_MAX`.
+            ///
+            /// The output range is typically inferred from the context in which the result is
+            /// used.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn widening_mul_ranged<
+                const OUT_MIN: $wide_internal,
+                const OUT_MAX: $wide_internal,
+                const RHS_MIN: $internal,
+                const RHS_MAX: $internal,
+            >(self, rhs: $type<RHS_MIN, RHS_MAX>) -> $wide<OUT_MIN, OUT_MAX> {
+                static_assert!(MIN <= MAX);
+                static_assert!(RHS_MIN <= RHS_MAX);
+                static_assert!(
+                    OUT_MIN
+                        <= bounds::$wide_internal::mul_min(
+                            MIN as $wide_internal,
+                            MAX as $wide_internal,
+                            RHS_MIN as $wide_internal,
+                            RHS_MAX as $wide_internal,
+                        )
+                );
+                static_assert!(
+                    bounds::$wide_internal::mul_max(
+                        MIN as $wide_internal,
+                        MAX as $wide_internal,
+                        RHS_MIN as $wide_internal,
+                        RHS_MAX as $wide_internal,
+                    ) <= OUT_MAX
+                );
+                // Safety: The compiler asserts that the result is in range. The multiplication
+                // cannot overflow, as the wider type can represent the product of any two values.
+                unsafe {
+                    $wide::new_unchecked(
+                        (self.get() as $wide_internal).unchecked_mul(rhs.get() as $wide_internal)
+                    )
+                }
+            }
+            )?
+
+            if_signed!($is_signed
+            /// Negation with a statically known output range. Computes `-self`, **failing to
+            /// compile** if the result is not guaranteed to be in the range `OUT_MIN..=OUT_MAX`.
+            ///
+            /// The output range is typically inferred from the context in which the result is
+            /// used.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn neg_ranged<
+                const OUT_MIN: $internal,
+                const OUT_MAX: $internal,
+            >(self) -> $type<OUT_MIN, OUT_MAX> {
+                static_assert!(MIN <= MAX);
+                static_assert!(OUT_MIN <= -MAX);
+                static_assert!(-MIN <= OUT_MAX);
+                // Safety: The compiler asserts that the result is in range.
+                // TODO(MSRV 1.93) use `unchecked_neg`
+                unsafe { $type::new_unchecked(self.get().checked_neg().unwrap_unchecked()) }
+            }
+
+            /// Absolute value with a statically known output range. Computes `self.abs()`,
+            /// **failing to compile** if the result is not guaranteed to be in the range
+            /// `OUT_MIN..=OUT_MAX`.
+            ///
+            /// The output range is typically inferred from the context in which the result is
+            /// used.
+            ///
+            /// ```rust
+            #[doc = concat!("# use deranged::", stringify!($type), ";")]
+            #[doc = concat!("let value = ", stringify!($type), "::<-10, 5>::new_static::<-7>();")]
+            #[doc = concat!("let abs: ", stringify!($type), "<0, 10> = value.abs_ranged();")]
+            /// assert_eq!(abs.get(), 7);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn abs_ranged<
+                const OUT_MIN: $internal,
+                const OUT_MAX: $internal,
+            >(self) -> $type<OUT_MIN, OUT_MAX> {
+                static_assert!(MIN <= MAX);
+                static_assert!(OUT_MIN <= bounds::$internal::abs_min(MIN, MAX));
+                static_assert!(bounds::$internal::abs_max(MIN, MAX) <= OUT_MAX);
+                // Safety: The compiler asserts that the result is in range.
+                unsafe { $type::new_unchecked(self.get().checked_abs().unwrap_unchecked()) }
+            });
+
+            /// Remainder with a statically known output range. Computes `self % rhs`, **failing to
+            /// compile** if `rhs` may be zero or if the result is not guaranteed to be in the range
+            /// `OUT_MIN..=OUT_MAX`.
+            ///
+            /// The result has the sign of `self` and a magnitude smaller than that of `rhs`, so the
+            /// output range must contain zero.
+            ///
+            /// The output range is typically inferred from the context in which the result is
+            /// used.
+            ///
+            /// ```rust
+            #[doc = concat!("# use deranged::", stringify!($type), ";")]
+            #[doc = concat!("let value = ", stringify!($type), "::<0, 100>::new_static::<42>();")]
+            #[doc = concat!("let divisor = ", stringify!($type), "::<5, 10>::new_static::<10>();")]
+            #[doc = concat!("let rem: ", stringify!($type), "<0, 9> = value.rem_ranged(divisor);")]
+            /// assert_eq!(rem.get(), 2);
+            /// ```
+            ///
+            /// A divisor that may be zero is rejected, even if the result would be in range
+            /// otherwise.
+            ///
+            /// ```rust,compile_fail
+            #[doc = concat!("# use deranged::", stringify!($type), ";")]
+            #[doc = concat!("let value = ", stringify!($type), "::<0, 0>::new_static::<0>();")]
+            #[doc = concat!("let divisor = ", stringify!($type), "::<0, 1>::new_static::<1>();")]
+            #[doc = concat!("let rem: ", stringify!($type), "<0, 0> = value.rem_ranged(divisor);")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn rem_ranged<
+                const OUT_MIN: $internal,
+                const OUT_MAX: $internal,
+                const RHS_MIN: $internal,
+                const RHS_MAX: $internal,
+            >(self, rhs: $type<RHS_MIN, RHS_MAX>) -> $type<OUT_MIN, OUT_MAX> {
+                static_assert!(MIN <= MAX);
+                static_assert!(RHS_MIN <= RHS_MAX);
+                static_assert!(OUT_MIN <= bounds::$internal::rem_min(MIN, RHS_MIN, RHS_MAX));
+                static_assert!(bounds::$internal::rem_max(MAX, RHS_MIN, RHS_MAX) <= OUT_MAX);
+                // Safety: The compiler asserts that the result is in range. The remainder only
+                // wraps for the smallest value of a signed type divided by -1, where it is zero.
+                unsafe { $type::new_unchecked(self.get().wrapping_rem(rhs.get())) }
+            }
+
+            /// Checked shift left. Computes `self << rhs`, returning `None` if the resulting value
             /// is out of range.
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_shl(rhs)))
             }
 
@@ -774,7 +1766,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_shl(self, rhs: u32) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range.
                 unsafe {
                     // TOD(MSRV 1.93) use `unchecked_shl`
@@ -787,7 +1779,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_shr(rhs)))
             }
 
@@ -800,7 +1792,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_shr(self, rhs: u32) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range.
                 unsafe {
                     // TODO(MSRV 1.93) use `unchecked_shr`
@@ -814,7 +1806,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_abs(self) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_abs()))
             }
 
@@ -828,24 +1820,44 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_abs(self) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range.
                 unsafe { Self::new_unchecked(self.get().checked_abs().unwrap_unchecked()) }
             }
 
             /// Absolute value. Computes `self.abs()`, **failing to compile** if the result is not
             /// guaranteed to be in range.
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline(always)]
             pub const fn abs(self) -> Self {
-                const {
-                    assert!(MIN <= MAX);
-                    assert!(MIN != $internal::MIN);
-                    assert!(-MIN <= MAX);
-                }
+                static_assert!(MIN <= MAX);
+                static_assert!(MIN != $internal::MIN);
+                static_assert!(-MIN <= MAX);
                 // <Self as $crate::traits::AbsIsSafe>::ASSERT;
                 // Safety: The compiler asserts that the result is in range.
                 unsafe { self.unchecked_abs() }
+            }
+
+            /// Absolute value. Computes `self.abs()`, with the range of the result inferred from
+            /// the range of `self`. **Fails to compile** if the range contains the minimum value of
+            #[doc = concat!("`", stringify!($internal), "`.")]
+            #[cfg(feature = "unstable-generic-const-exprs")]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn abs(
+                self,
+            ) -> $type<
+                { bounds::$internal::abs_min(MIN, MAX) },
+                { bounds::$internal::abs_max(MIN, MAX) },
+            >
+            where
+                $type<
+                    { bounds::$internal::abs_min(MIN, MAX) },
+                    { bounds::$internal::abs_max(MIN, MAX) },
+                >: Sized,
+            {
+                self.abs_ranged()
             });
 
             /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if the resulting
@@ -853,7 +1865,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn checked_pow(self, exp: u32) -> Option<Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new(const_try_opt!(self.get().checked_pow(exp)))
             }
 
@@ -867,7 +1879,7 @@ macro_rules! impl_ranged {
             #[track_caller]
             #[inline(always)]
             pub const unsafe fn unchecked_pow(self, exp: u32) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the result is in range.
                 unsafe {
                     Self::new_unchecked(self.get().checked_pow(exp).unwrap_unchecked())
@@ -879,7 +1891,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn saturating_add(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new_saturating(self.get().saturating_add(rhs))
             }
 
@@ -888,7 +1900,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn saturating_sub(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new_saturating(self.get().saturating_sub(rhs))
             }
 
@@ -898,7 +1910,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn saturating_neg(self) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new_saturating(self.get().saturating_neg())
             });
 
@@ -907,7 +1919,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn saturating_abs(self) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new_saturating(self.get().saturating_abs())
             });
 
@@ -916,7 +1928,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn saturating_mul(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new_saturating(self.get().saturating_mul(rhs))
             }
 
@@ -925,7 +1937,7 @@ macro_rules! impl_ranged {
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn saturating_pow(self, exp: u32) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::new_saturating(self.get().saturating_pow(exp))
             }
 
@@ -934,7 +1946,7 @@ macro_rules! impl_ranged {
                 /// negative.
                 #[inline]
                 pub const fn is_positive(self) -> bool {
-                    const { assert!(MIN <= MAX); }
+                    static_assert!(MIN <= MAX);
                     self.get().is_positive()
                 }
 
@@ -942,7 +1954,7 @@ macro_rules! impl_ranged {
                 /// positive.
                 #[inline]
                 pub const fn is_negative(self) -> bool {
-                    const { assert!(MIN <= MAX); }
+                    static_assert!(MIN <= MAX);
                     self.get().is_negative()
                 }
             }
@@ -982,7 +1994,7 @@ macro_rules! impl_ranged {
             #[inline]
             #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
             pub const fn wrapping_add(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Forward to internal type's impl if same as type.
                 if MIN == $internal::MIN && MAX == $internal::MAX {
                     // Safety: std's wrapping methods match ranged arithmetic when the range is the internal datatype's range.
@@ -1026,53 +2038,517 @@ macro_rules! impl_ranged {
                 }
             }
 
-            /// Wrapping integer subtraction. Computes `self - rhs`, wrapping around the numeric
-            /// bounds.
+            /// Wrapping integer subtraction. Computes `self - rhs`, wrapping around the numeric
+            /// bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
+            pub const fn wrapping_sub(self, rhs: $internal) -> Self {
+                static_assert!(MIN <= MAX);
+                // Forward to internal type's impl if same as type.
+                if MIN == $internal::MIN && MAX == $internal::MAX {
+                    // Safety: std's wrapping methods match ranged arithmetic when the range is the internal datatype's range.
+                    return unsafe { Self::new_unchecked(self.get().wrapping_sub(rhs)) }
+                }
+
+                let inner = self.get();
+
+                // Won't overflow because of std impl forwarding.
+                let range_len = MAX.abs_diff(MIN) + 1;
+
+                // Calculate the offset with proper handling for negative rhs
+                let offset = Self::rem_euclid_unsigned(rhs, range_len);
+
+                let lesser_vals = MIN.abs_diff(inner);
+                // No wrap
+                if offset <= lesser_vals {
+                    // Safety:
+                    // if inner >= 0 -> No overflow beyond range (offset <= greater_vals)
+                    // if inner < 0: Same as >=0 with caveat:
+                    // `(signed as unsigned).wrapping_sub(unsigned) as signed` is the same as
+                    // `signed::checked_sub_unsigned(unsigned).unwrap()` or `wrapping_sub_unsigned`
+                    // (the difference doesn't matter since it won't overflow below 0),
+                    // but unsigned integers don't have either method so it won't compile that way.
+                    unsafe { Self::new_unchecked(
+                        ((inner as $unsigned_type).wrapping_sub(offset)) as $internal
+                    ) }
+                }
+                // Wrap
+                else {
+                    // Safety:
+                    // - offset < range_len by rem_euclid (MAX - ... safe)
+                    // - offset > lesser_vals from if statement (offset - (lesser_vals + 1) safe)
+                    //
+                    // again using `(signed as unsigned).wrapping_sub(unsigned) as signed` = `checked_sub_unsigned` trick
+                    unsafe { Self::new_unchecked(
+                        ((MAX as $unsigned_type).wrapping_sub(
+                            offset - (lesser_vals + 1)
+                        )) as $internal
+                    ) }
+                }
+            }
+
+            /// Compute `(lhs * rhs) % range_len` without overflow. Both `lhs` and `rhs` must be
+            /// less than `range_len`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            const fn mul_rem_unsigned(
+                lhs: $unsigned_type,
+                rhs: $unsigned_type,
+                range_len: $unsigned_type,
+            ) -> $unsigned_type {
+                /// Compute `(lhs + rhs) % range_len` where both operands are less than
+                /// `range_len`.
+                const fn add_rem(
+                    lhs: $unsigned_type,
+                    rhs: $unsigned_type,
+                    range_len: $unsigned_type,
+                ) -> $unsigned_type {
+                    let headroom = range_len - rhs;
+                    if lhs >= headroom { lhs - headroom } else { lhs + rhs }
+                }
+
+                // Double-and-add so that no intermediate value exceeds `range_len * 2`.
+                let mut result = 0;
+                let mut lhs = lhs;
+                let mut rhs = rhs;
+                while rhs != 0 {
+                    if rhs & 1 == 1 {
+                        result = add_rem(result, lhs, range_len);
+                    }
+                    lhs = add_rem(lhs, lhs, range_len);
+                    rhs >>= 1;
+                }
+                result
+            }
+
+            /// Obtain the value in `MIN..=MAX` that is congruent to `residue` modulo
+            /// `range_len`. `residue` must be less than `range_len`.
+            #[must_use]
+            #[inline]
+            #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
+            const fn from_residue(residue: $unsigned_type, range_len: $unsigned_type) -> Self {
+                let min_residue = Self::rem_euclid_unsigned(MIN, range_len);
+                let offset = if residue >= min_residue {
+                    residue - min_residue
+                } else {
+                    range_len - (min_residue - residue)
+                };
+                // Safety: `offset < range_len`, so `MIN + offset <= MAX`. The cast trick is the
+                // same as in `wrapping_add`.
+                unsafe { Self::new_unchecked(
+                    ((MIN as $unsigned_type).wrapping_add(offset)) as $internal
+                ) }
+            }
+
+            /// Wrapping integer multiplication. Computes `self * rhs`, wrapping around the
+            /// numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn wrapping_mul(self, rhs: $internal) -> Self {
+                static_assert!(MIN <= MAX);
+                // Forward to internal type's impl if same as type.
+                if MIN == $internal::MIN && MAX == $internal::MAX {
+                    // Safety: std's wrapping methods match ranged arithmetic when the range is the internal datatype's range.
+                    return unsafe { Self::new_unchecked(self.get().wrapping_mul(rhs)) }
+                }
+
+                // Won't overflow because of std impl forwarding.
+                let range_len = MAX.abs_diff(MIN) + 1;
+                let product = Self::mul_rem_unsigned(
+                    Self::rem_euclid_unsigned(self.get(), range_len),
+                    Self::rem_euclid_unsigned(rhs, range_len),
+                    range_len,
+                );
+                Self::from_residue(product, range_len)
+            }
+
+            /// Wrapping negation. Computes `-self`, wrapping around the numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn wrapping_neg(self) -> Self {
+                static_assert!(MIN <= MAX);
+                // Forward to internal type's impl if same as type.
+                if MIN == $internal::MIN && MAX == $internal::MAX {
+                    // Safety: std's wrapping methods match ranged arithmetic when the range is the internal datatype's range.
+                    return unsafe { Self::new_unchecked(self.get().wrapping_neg()) }
+                }
+
+                // Won't overflow because of std impl forwarding.
+                let range_len = MAX.abs_diff(MIN) + 1;
+                let residue = Self::rem_euclid_unsigned(self.get(), range_len);
+                let negated = if residue == 0 { 0 } else { range_len - residue };
+                Self::from_residue(negated, range_len)
+            }
+
+            /// Wrapping integer exponentiation. Computes `self.pow(exp)`, wrapping around the
+            /// numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn wrapping_pow(self, exp: u32) -> Self {
+                static_assert!(MIN <= MAX);
+                // Forward to internal type's impl if same as type.
+                if MIN == $internal::MIN && MAX == $internal::MAX {
+                    // Safety: std's wrapping methods match ranged arithmetic when the range is the internal datatype's range.
+                    return unsafe { Self::new_unchecked(self.get().wrapping_pow(exp)) }
+                }
+
+                // Won't overflow because of std impl forwarding.
+                let range_len = MAX.abs_diff(MIN) + 1;
+                let mut base = Self::rem_euclid_unsigned(self.get(), range_len);
+                let mut exp = exp;
+                let mut result = 1 % range_len;
+                while exp != 0 {
+                    if exp & 1 == 1 {
+                        result = Self::mul_rem_unsigned(result, base, range_len);
+                    }
+                    base = Self::mul_rem_unsigned(base, base, range_len);
+                    exp >>= 1;
+                }
+                Self::from_residue(result, range_len)
+            }
+
+            /// Wrapping (panic-free) shift left. Computes `self << rhs`, where `rhs` is masked to
+            /// the bit width of the internal type, wrapping around the numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn wrapping_shl(self, rhs: u32) -> Self {
+                static_assert!(MIN <= MAX);
+                // Forward to internal type's impl if same as type.
+                if MIN == $internal::MIN && MAX == $internal::MAX {
+                    // Safety: std's wrapping methods match ranged arithmetic when the range is the internal datatype's range.
+                    return unsafe { Self::new_unchecked(self.get().wrapping_shl(rhs)) }
+                }
+
+                // Won't overflow because of std impl forwarding.
+                let range_len = MAX.abs_diff(MIN) + 1;
+                // Shifting left is multiplication by a power of two, which always fits in the
+                // unsigned type once the shift amount is masked.
+                let factor = $unsigned_type::wrapping_shl(1, rhs) % range_len;
+                let product = Self::mul_rem_unsigned(
+                    Self::rem_euclid_unsigned(self.get(), range_len),
+                    factor,
+                    range_len,
+                );
+                Self::from_residue(product, range_len)
+            }
+
+            /// Wrapping (panic-free) shift right. Computes `self >> rhs`, where `rhs` is masked to
+            /// the bit width of the internal type, wrapping around the numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn wrapping_shr(self, rhs: u32) -> Self {
+                static_assert!(MIN <= MAX);
+                // Forward to internal type's impl if same as type.
+                if MIN == $internal::MIN && MAX == $internal::MAX {
+                    // Safety: std's wrapping methods match ranged arithmetic when the range is the internal datatype's range.
+                    return unsafe { Self::new_unchecked(self.get().wrapping_shr(rhs)) }
+                }
+
+                // Won't overflow because of std impl forwarding.
+                let range_len = MAX.abs_diff(MIN) + 1;
+                Self::from_residue(
+                    Self::rem_euclid_unsigned(self.get().wrapping_shr(rhs), range_len),
+                    range_len,
+                )
+            }
+
+            if_signed!($is_signed
+            /// Wrapping absolute value. Computes `self.abs()`, wrapping around the numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn wrapping_abs(self) -> Self {
+                static_assert!(MIN <= MAX);
+                if self.get() < 0 {
+                    self.wrapping_neg()
+                } else {
+                    self
+                }
+            });
+
+            /// Calculates `self + rhs`. Returns a tuple of the addition along with a boolean
+            /// indicating whether the result wrapped around the numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn overflowing_add(self, rhs: $internal) -> (Self, bool) {
+                static_assert!(MIN <= MAX);
+                match self.checked_add(rhs) {
+                    Some(value) => (value, false),
+                    None => (self.wrapping_add(rhs), true),
+                }
+            }
+
+            /// Calculates `self - rhs`. Returns a tuple of the subtraction along with a boolean
+            /// indicating whether the result wrapped around the numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn overflowing_sub(self, rhs: $internal) -> (Self, bool) {
+                static_assert!(MIN <= MAX);
+                match self.checked_sub(rhs) {
+                    Some(value) => (value, false),
+                    None => (self.wrapping_sub(rhs), true),
+                }
+            }
+
+            /// Calculates `self * rhs`. Returns a tuple of the multiplication along with a boolean
+            /// indicating whether the result wrapped around the numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn overflowing_mul(self, rhs: $internal) -> (Self, bool) {
+                static_assert!(MIN <= MAX);
+                match self.checked_mul(rhs) {
+                    Some(value) => (value, false),
+                    None => (self.wrapping_mul(rhs), true),
+                }
+            }
+
+            /// Calculates `-self`. Returns a tuple of the negation along with a boolean indicating
+            /// whether the result wrapped around the numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn overflowing_neg(self) -> (Self, bool) {
+                static_assert!(MIN <= MAX);
+                match self.checked_neg() {
+                    Some(value) => (value, false),
+                    None => (self.wrapping_neg(), true),
+                }
+            }
+
+            /// Calculates `self.pow(exp)`. Returns a tuple of the exponentiation along with a
+            /// boolean indicating whether the result wrapped around the numeric bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                static_assert!(MIN <= MAX);
+                match self.checked_pow(exp) {
+                    Some(value) => (value, false),
+                    None => (self.wrapping_pow(exp), true),
+                }
+            }
+
+            /// Returns `true` if the value is the smallest value that can be represented by this
+            /// type.
+            #[inline]
+            pub const fn is_min(self) -> bool {
+                static_assert!(MIN <= MAX);
+                self.get() == MIN
+            }
+
+            /// Returns `true` if the value is the largest value that can be represented by this
+            /// type.
+            #[inline]
+            pub const fn is_max(self) -> bool {
+                static_assert!(MIN <= MAX);
+                self.get() == MAX
+            }
+
+            /// Checked successor. Computes `self + 1`, returning `None` if the resulting value is
+            /// out of range.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn checked_succ(self) -> Option<Self> {
+                static_assert!(MIN <= MAX);
+                if self.is_max() {
+                    None
+                } else {
+                    // Safety: `self < MAX`, so `self + 1` neither overflows nor exceeds `MAX`.
+                    Some(unsafe { Self::new_unchecked(self.get() + 1) })
+                }
+            }
+
+            /// Checked predecessor. Computes `self - 1`, returning `None` if the resulting value
+            /// is out of range.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn checked_pred(self) -> Option<Self> {
+                static_assert!(MIN <= MAX);
+                if self.is_min() {
+                    None
+                } else {
+                    // Safety: `self > MIN`, so `self - 1` neither overflows nor falls below `MIN`.
+                    Some(unsafe { Self::new_unchecked(self.get() - 1) })
+                }
+            }
+
+            /// Wrapping successor. Computes `self + 1`, wrapping around to `MIN` if the value is
+            /// `MAX`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn wrapping_succ(self) -> Self {
+                static_assert!(MIN <= MAX);
+                match self.checked_succ() {
+                    Some(value) => value,
+                    None => Self::MIN,
+                }
+            }
+
+            /// Wrapping predecessor. Computes `self - 1`, wrapping around to `MAX` if the value is
+            /// `MIN`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn wrapping_pred(self) -> Self {
+                static_assert!(MIN <= MAX);
+                match self.checked_pred() {
+                    Some(value) => value,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Saturating successor. Computes `self + 1`, remaining at `MAX` if the value is
+            /// already `MAX`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn saturating_succ(self) -> Self {
+                static_assert!(MIN <= MAX);
+                match self.checked_succ() {
+                    Some(value) => value,
+                    None => self,
+                }
+            }
+
+            /// Saturating predecessor. Computes `self - 1`, remaining at `MIN` if the value is
+            /// already `MIN`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn saturating_pred(self) -> Self {
+                static_assert!(MIN <= MAX);
+                match self.checked_pred() {
+                    Some(value) => value,
+                    None => self,
+                }
+            }
+
+            /// Convert a value of the internal type to the representation used by errors.
+            #[must_use]
+            #[inline]
+            #[allow(trivial_numeric_casts)] // needed since some casts are to the same type
+            const fn to_error_int(value: $internal) -> ErrorInt {
+                if $internal::MIN == 0 {
+                    ErrorInt::Unsigned(value as u128)
+                } else {
+                    ErrorInt::Signed(value as i128)
+                }
+            }
+
+            /// Create an error for an operation on this type.
+            #[must_use]
+            #[inline]
+            const fn range_error(kind: RangeErrorKind, value: Option<$internal>) -> RangeError {
+                RangeError {
+                    kind,
+                    value: match value {
+                        Some(value) => Some(Self::to_error_int(value)),
+                        None => None,
+                    },
+                    min: Self::to_error_int(MIN),
+                    max: Self::to_error_int(MAX),
+                }
+            }
+
+            /// Creates a ranged integer if the given value is in the range `MIN..=MAX`, returning
+            /// an error describing why it is not otherwise.
+            #[inline]
+            const fn try_new(value: $internal) -> Result<Self, RangeError> {
+                if value < MIN {
+                    Err(Self::range_error(RangeErrorKind::BelowMin, Some(value)))
+                } else if value > MAX {
+                    Err(Self::range_error(RangeErrorKind::AboveMax, Some(value)))
+                } else {
+                    // Safety: The value was checked to be in range.
+                    Ok(unsafe { Self::new_unchecked(value) })
+                }
+            }
+
+            /// Fallible integer addition. Computes `self + rhs`, returning an error describing
+            /// why the result is out of range if it is.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            #[allow(unused_comparisons)]
+            pub const fn try_add(self, rhs: $internal) -> Result<Self, RangeError> {
+                static_assert!(MIN <= MAX);
+                match self.get().checked_add(rhs) {
+                    Some(value) => Self::try_new(value),
+                    None if rhs < 0 => Err(Self::range_error(RangeErrorKind::BelowMin, None)),
+                    None => Err(Self::range_error(RangeErrorKind::AboveMax, None)),
+                }
+            }
+
+            /// Fallible integer subtraction. Computes `self - rhs`, returning an error describing
+            /// why the result is out of range if it is.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            #[allow(unused_comparisons)]
+            pub const fn try_sub(self, rhs: $internal) -> Result<Self, RangeError> {
+                static_assert!(MIN <= MAX);
+                match self.get().checked_sub(rhs) {
+                    Some(value) => Self::try_new(value),
+                    None if rhs < 0 => Err(Self::range_error(RangeErrorKind::AboveMax, None)),
+                    None => Err(Self::range_error(RangeErrorKind::BelowMin, None)),
+                }
+            }
+
+            /// Fallible integer multiplication. Computes `self * rhs`, returning an error
+            /// describing why the result is out of range if it is.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            #[allow(unused_comparisons)]
+            pub const fn try_mul(self, rhs: $internal) -> Result<Self, RangeError> {
+                static_assert!(MIN <= MAX);
+                match self.get().checked_mul(rhs) {
+                    Some(value) => Self::try_new(value),
+                    None if (self.get() < 0) != (rhs < 0) => {
+                        Err(Self::range_error(RangeErrorKind::BelowMin, None))
+                    }
+                    None => Err(Self::range_error(RangeErrorKind::AboveMax, None)),
+                }
+            }
+
+            /// Fallible integer division. Computes `self / rhs`, returning an error if `rhs == 0`
+            /// or describing why the result is out of range if it is.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn try_div(self, rhs: $internal) -> Result<Self, RangeError> {
+                static_assert!(MIN <= MAX);
+                if rhs == 0 {
+                    return Err(Self::range_error(RangeErrorKind::DivisionByZero, None));
+                }
+                match self.get().checked_div(rhs) {
+                    Some(value) => Self::try_new(value),
+                    // The only other way for division to overflow is `MIN / -1`.
+                    None => Err(Self::range_error(RangeErrorKind::AboveMax, None)),
+                }
+            }
+
+            /// Fallible integer exponentiation. Computes `self.pow(exp)`, returning an error
+            /// describing why the result is out of range if it is.
             #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
-            #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
-            pub const fn wrapping_sub(self, rhs: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
-                // Forward to internal type's impl if same as type.
-                if MIN == $internal::MIN && MAX == $internal::MAX {
-                    // Safety: std's wrapping methods match ranged arithmetic when the range is the internal datatype's range.
-                    return unsafe { Self::new_unchecked(self.get().wrapping_sub(rhs)) }
+            #[allow(unused_comparisons)]
+            pub const fn try_pow(self, exp: u32) -> Result<Self, RangeError> {
+                static_assert!(MIN <= MAX);
+                match self.get().checked_pow(exp) {
+                    Some(value) => Self::try_new(value),
+                    None if self.get() < 0 && exp % 2 == 1 => {
+                        Err(Self::range_error(RangeErrorKind::BelowMin, None))
+                    }
+                    None => Err(Self::range_error(RangeErrorKind::AboveMax, None)),
                 }
+            }
 
-                let inner = self.get();
-
-                // Won't overflow because of std impl forwarding.
-                let range_len = MAX.abs_diff(MIN) + 1;
-
-                // Calculate the offset with proper handling for negative rhs
-                let offset = Self::rem_euclid_unsigned(rhs, range_len);
-
-                let lesser_vals = MIN.abs_diff(inner);
-                // No wrap
-                if offset <= lesser_vals {
-                    // Safety:
-                    // if inner >= 0 -> No overflow beyond range (offset <= greater_vals)
-                    // if inner < 0: Same as >=0 with caveat:
-                    // `(signed as unsigned).wrapping_sub(unsigned) as signed` is the same as
-                    // `signed::checked_sub_unsigned(unsigned).unwrap()` or `wrapping_sub_unsigned`
-                    // (the difference doesn't matter since it won't overflow below 0),
-                    // but unsigned integers don't have either method so it won't compile that way.
-                    unsafe { Self::new_unchecked(
-                        ((inner as $unsigned_type).wrapping_sub(offset)) as $internal
-                    ) }
-                }
-                // Wrap
-                else {
-                    // Safety:
-                    // - offset < range_len by rem_euclid (MAX - ... safe)
-                    // - offset > lesser_vals from if statement (offset - (lesser_vals + 1) safe)
-                    //
-                    // again using `(signed as unsigned).wrapping_sub(unsigned) as signed` = `checked_sub_unsigned` trick
-                    unsafe { Self::new_unchecked(
-                        ((MAX as $unsigned_type).wrapping_sub(
-                            offset - (lesser_vals + 1)
-                        )) as $internal
-                    ) }
+            /// Fallible negation. Computes `-self`, returning an error describing why the result
+            /// is out of range if it is.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn try_neg(self) -> Result<Self, RangeError> {
+                static_assert!(MIN <= MAX);
+                match self.get().checked_neg() {
+                    Some(value) => Self::try_new(value),
+                    // Negating a positive unsigned integer is negative, while negating the minimum
+                    // value of a signed integer is too large.
+                    None if self.get() > 0 => {
+                        Err(Self::range_error(RangeErrorKind::BelowMin, None))
+                    }
+                    None => Err(Self::range_error(RangeErrorKind::AboveMax, None)),
                 }
             }
         }
@@ -1093,14 +2569,14 @@ macro_rules! impl_ranged {
             #[allow(non_snake_case)]
             #[inline(always)]
             pub const fn Some(value: $type<MIN, MAX>) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self(value.get())
             }
 
             /// Returns the value as the standard library's [`Option`] type.
             #[inline(always)]
             pub const fn get(self) -> Option<$type<MIN, MAX>> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 if self.0 == Self::NICHE {
                     None
                 } else {
@@ -1119,7 +2595,7 @@ macro_rules! impl_ranged {
             #[inline(always)]
             #[track_caller]
             pub const unsafe fn some_unchecked(value: $internal) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The caller must ensure that the value is in range.
                 unsafe { assert_unchecked(MIN <= value && value <= MAX) };
                 Self(value)
@@ -1128,7 +2604,7 @@ macro_rules! impl_ranged {
             /// Obtain the inner value of the struct. This is useful for comparisons.
             #[inline(always)]
             pub(crate) const fn inner(self) -> $internal {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.0
             }
 
@@ -1142,7 +2618,7 @@ macro_rules! impl_ranged {
             )]
             #[inline(always)]
             pub const fn get_primitive(self) -> Option<$internal> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Some(const_try_opt!(self.get()).get())
             }
 
@@ -1157,21 +2633,21 @@ macro_rules! impl_ranged {
             /// to optimization.
             #[inline(always)]
             pub const fn get_primitive_without_hint(self) -> Option<$internal> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Some(const_try_opt!(self.get()).get_without_hint())
             }
 
             /// Returns `true` if the value is the niche value.
             #[inline(always)]
             pub const fn is_none(&self) -> bool {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().is_none()
             }
 
             /// Returns `true` if the value is not the niche value.
             #[inline(always)]
             pub const fn is_some(&self) -> bool {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().is_some()
             }
         }
@@ -1179,7 +2655,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> fmt::Debug for $type<MIN, MAX> {
             #[inline(always)]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().fmt(f)
             }
         }
@@ -1187,7 +2663,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> fmt::Debug for $optional_type<MIN, MAX> {
             #[inline(always)]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().fmt(f)
             }
         }
@@ -1195,7 +2671,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> fmt::Display for $type<MIN, MAX> {
             #[inline(always)]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().fmt(f)
             }
         }
@@ -1212,7 +2688,7 @@ macro_rules! impl_ranged {
                 &self,
                 f: smart_display::FormatterOptions,
             ) -> smart_display::Metadata<'_, Self> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get_ref().metadata(f).reuse()
             }
 
@@ -1222,7 +2698,7 @@ macro_rules! impl_ranged {
                 f: &mut fmt::Formatter<'_>,
                 metadata: smart_display::Metadata<'_, Self>,
             ) -> fmt::Result {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().fmt_with_metadata(f, metadata.reuse())
             }
         }
@@ -1230,7 +2706,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> Default for $optional_type<MIN, MAX> {
             #[inline(always)]
             fn default() -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::None
             }
         }
@@ -1238,7 +2714,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> AsRef<$internal> for $type<MIN, MAX> {
             #[inline(always)]
             fn as_ref(&self) -> &$internal {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 &self.get_ref()
             }
         }
@@ -1246,7 +2722,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> Borrow<$internal> for $type<MIN, MAX> {
             #[inline(always)]
             fn borrow(&self) -> &$internal {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 &self.get_ref()
             }
         }
@@ -1259,10 +2735,8 @@ macro_rules! impl_ranged {
         > PartialEq<$type<MIN_B, MAX_B>> for $type<MIN_A, MAX_A> {
             #[inline(always)]
             fn eq(&self, other: &$type<MIN_B, MAX_B>) -> bool {
-                const {
-                    assert!(MIN_A <= MAX_A);
-                    assert!(MIN_B <= MAX_B);
-                }
+                static_assert!(MIN_A <= MAX_A);
+                static_assert!(MIN_B <= MAX_B);
                 self.get() == other.get()
             }
         }
@@ -1275,10 +2749,8 @@ macro_rules! impl_ranged {
         > PartialEq<$optional_type<MIN_B, MAX_B>> for $optional_type<MIN_A, MAX_A> {
             #[inline(always)]
             fn eq(&self, other: &$optional_type<MIN_B, MAX_B>) -> bool {
-                const {
-                    assert!(MIN_A <= MAX_A);
-                    assert!(MIN_B <= MAX_B);
-                }
+                static_assert!(MIN_A <= MAX_A);
+                static_assert!(MIN_B <= MAX_B);
                 self.inner() == other.inner()
             }
         }
@@ -1291,10 +2763,8 @@ macro_rules! impl_ranged {
         > PartialOrd<$type<MIN_B, MAX_B>> for $type<MIN_A, MAX_A> {
             #[inline(always)]
             fn partial_cmp(&self, other: &$type<MIN_B, MAX_B>) -> Option<Ordering> {
-                const {
-                    assert!(MIN_A <= MAX_A);
-                    assert!(MIN_B <= MAX_B);
-                }
+                static_assert!(MIN_A <= MAX_A);
+                static_assert!(MIN_B <= MAX_B);
                 self.get().partial_cmp(&other.get())
             }
         }
@@ -1307,10 +2777,8 @@ macro_rules! impl_ranged {
         > PartialOrd<$optional_type<MIN_B, MAX_B>> for $optional_type<MIN_A, MAX_A> {
             #[inline]
             fn partial_cmp(&self, other: &$optional_type<MIN_B, MAX_B>) -> Option<Ordering> {
-                const {
-                    assert!(MIN_A <= MAX_A);
-                    assert!(MIN_B <= MAX_B);
-                }
+                static_assert!(MIN_A <= MAX_A);
+                static_assert!(MIN_B <= MAX_B);
                 if self.is_none() && other.is_none() {
                     Some(Ordering::Equal)
                 } else if self.is_none() {
@@ -1329,7 +2797,7 @@ macro_rules! impl_ranged {
         > Ord for $optional_type<MIN, MAX> {
             #[inline]
             fn cmp(&self, other: &Self) -> Ordering {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 if self.is_none() && other.is_none() {
                     Ordering::Equal
                 } else if self.is_none() {
@@ -1345,7 +2813,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> fmt::Binary for $type<MIN, MAX> {
             #[inline(always)]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().fmt(f)
             }
         }
@@ -1353,7 +2821,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> fmt::LowerHex for $type<MIN, MAX> {
             #[inline(always)]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().fmt(f)
             }
         }
@@ -1361,7 +2829,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> fmt::UpperHex for $type<MIN, MAX> {
             #[inline(always)]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().fmt(f)
             }
         }
@@ -1369,7 +2837,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> fmt::LowerExp for $type<MIN, MAX> {
             #[inline(always)]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().fmt(f)
             }
         }
@@ -1377,7 +2845,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> fmt::UpperExp for $type<MIN, MAX> {
             #[inline(always)]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().fmt(f)
             }
         }
@@ -1385,7 +2853,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> fmt::Octal for $type<MIN, MAX> {
             #[inline(always)]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().fmt(f)
             }
         }
@@ -1409,7 +2877,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> From<$type<MIN, MAX>> for $internal {
             #[inline(always)]
             fn from(value: $type<MIN, MAX>) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 value.get()
             }
         }
@@ -1420,7 +2888,7 @@ macro_rules! impl_ranged {
         > From<$type<MIN, MAX>> for $optional_type<MIN, MAX> {
             #[inline(always)]
             fn from(value: $type<MIN, MAX>) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::Some(value)
             }
         }
@@ -1431,7 +2899,7 @@ macro_rules! impl_ranged {
         > From<Option<$type<MIN, MAX>>> for $optional_type<MIN, MAX> {
             #[inline(always)]
             fn from(value: Option<$type<MIN, MAX>>) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 match value {
                     Some(value) => Self::Some(value),
                     None => Self::None,
@@ -1445,7 +2913,7 @@ macro_rules! impl_ranged {
         > From<$optional_type<MIN, MAX>> for Option<$type<MIN, MAX>> {
             #[inline(always)]
             fn from(value: $optional_type<MIN, MAX>) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 value.get()
             }
         }
@@ -1455,8 +2923,21 @@ macro_rules! impl_ranged {
 
             #[inline]
             fn try_from(value: $internal) -> Result<Self, Self::Error> {
-                const { assert!(MIN <= MAX); }
-                Self::new(value).ok_or(TryFromIntError)
+                static_assert!(MIN <= MAX);
+                let kind = if value < MIN {
+                    RangeErrorKind::BelowMin
+                } else if value > MAX {
+                    RangeErrorKind::AboveMax
+                } else {
+                    // Safety: The value was checked to be in range.
+                    return Ok(unsafe { Self::new_unchecked(value) });
+                };
+                Err(TryFromIntError {
+                    kind,
+                    value: Self::to_error_int(value),
+                    min: Self::to_error_int(MIN),
+                    max: Self::to_error_int(MAX),
+                })
             }
         }
 
@@ -1465,19 +2946,187 @@ macro_rules! impl_ranged {
 
             #[inline]
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                const { assert!(MIN <= MAX); }
-                let value = s.parse::<$internal>().map_err(|e| ParseIntError {
-                    kind: e.kind().clone()
-                })?;
-                if value < MIN {
-                    Err(ParseIntError { kind: IntErrorKind::NegOverflow })
-                } else if value > MAX {
-                    Err(ParseIntError { kind: IntErrorKind::PosOverflow })
+                static_assert!(MIN <= MAX);
+                Self::from_str_radix(s, 10)
+            }
+        }
+
+        impl_binary_op! {
+            ranged
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
+            $type $internal:
+            Add add AddAssign add_assign checked_add "attempt to add with overflow"
+        }
+        impl_binary_op! {
+            ranged
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
+            $type $internal:
+            Sub sub SubAssign sub_assign checked_sub "attempt to subtract with overflow"
+        }
+        impl_binary_op! {
+            ranged
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
+            $type $internal:
+            Mul mul MulAssign mul_assign checked_mul "attempt to multiply with overflow"
+        }
+        impl_binary_op! {
+            ranged $type $internal:
+            Div div DivAssign div_assign checked_div
+            "attempt to divide by zero or with overflow"
+        }
+        impl_binary_op! {
+            ranged
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
+            $type $internal:
+            Rem rem RemAssign rem_assign checked_rem
+            "attempt to calculate the remainder with a divisor of zero or with overflow"
+        }
+        impl_binary_op! {
+            $type $internal u32:
+            Shl shl ShlAssign shl_assign checked_shl "attempt to shift left with overflow"
+        }
+        impl_binary_op! {
+            $type $internal u32:
+            Shr shr ShrAssign shr_assign checked_shr "attempt to shift right with overflow"
+        }
+
+        impl_inferred_op! {
+            $type $internal: Add add add_ranged: MIN + RHS_MIN, MAX + RHS_MAX
+        }
+        impl_inferred_op! {
+            $type $internal: Sub sub sub_ranged: MIN - RHS_MAX, MAX - RHS_MIN
+        }
+        impl_inferred_op! {
+            $type $internal: Mul mul mul_ranged:
+            bounds::$internal::mul_min(MIN, MAX, RHS_MIN, RHS_MAX),
+            bounds::$internal::mul_max(MIN, MAX, RHS_MIN, RHS_MAX),
+        }
+        impl_inferred_op! {
+            $type $internal: Rem rem rem_ranged:
+            bounds::$internal::rem_min(MIN, RHS_MIN, RHS_MAX),
+            bounds::$internal::rem_max(MAX, RHS_MIN, RHS_MAX),
+        }
+
+        if_signed! { $is_signed
+            #[cfg(feature = "unstable-generic-const-exprs")]
+            impl<const MIN: $internal, const MAX: $internal> Neg for $type<MIN, MAX>
+            where
+                $type<{ -MAX }, { -MIN }>: Sized,
+            {
+                type Output = $type<{ -MAX }, { -MIN }>;
+
+                #[inline]
+                fn neg(self) -> Self::Output {
+                    self.neg_ranged()
+                }
+            }
+
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
+            impl<const MIN: $internal, const MAX: $internal> Neg for $type<MIN, MAX> {
+                type Output = Self;
+
+                #[track_caller]
+                #[inline]
+                fn neg(self) -> Self {
+                    static_assert!(MIN <= MAX);
+                    match self.checked_neg() {
+                        Some(value) => value,
+                        None => panic!("attempt to negate with overflow"),
+                    }
+                }
+            }
+        }
+
+        impl_wrapper_op! {
+            Modular $type $internal: Add add AddAssign add_assign wrapping_add
+        }
+        impl_wrapper_op! {
+            Modular $type $internal: Sub sub SubAssign sub_assign wrapping_sub
+        }
+        impl_wrapper_op! {
+            Modular $type $internal: Mul mul MulAssign mul_assign wrapping_mul
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> Neg for Modular<$type<MIN, MAX>> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                static_assert!(MIN <= MAX);
+                Modular(self.0.wrapping_neg())
+            }
+        }
+
+        impl_wrapper_op! {
+            Modular $type $internal: Sum "the value congruent to zero modulo the length of the range"
+            |iter| Modular(iter.fold($type::MIN.wrapping_sub(MIN), |sum, value| {
+                sum.wrapping_add(value.0.get())
+            }))
+        }
+
+        impl_wrapper_op! {
+            Saturating $type $internal: Add add AddAssign add_assign saturating_add
+        }
+        impl_wrapper_op! {
+            Saturating $type $internal: Sub sub SubAssign sub_assign saturating_sub
+        }
+        impl_wrapper_op! {
+            Saturating $type $internal: Mul mul MulAssign mul_assign saturating_mul
+        }
+
+        if_signed! { $is_signed
+            impl<const MIN: $internal, const MAX: $internal> Neg for Saturating<$type<MIN, MAX>> {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    static_assert!(MIN <= MAX);
+                    Saturating(self.0.saturating_neg())
+                }
+            }
+        }
+
+        impl_wrapper_op! {
+            Saturating $type $internal: Sum "zero, clamped to the range"
+            // Zero need not be in the range, so the sum is accumulated in the inner type.
+            |iter| Saturating($type::new_saturating(iter.fold(0, |sum, value| {
+                value.0.saturating_add(sum).get()
+            })))
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> Modular<$type<MIN, MAX>> {
+            /// The number of steps needed to reach `other` by repeatedly incrementing `self`,
+            /// wrapping around the bounds.
+            ///
+            /// ```rust
+            #[doc = concat!("# use deranged::{Modular, ", stringify!($type), "};")]
+            #[doc = concat!("let start = Modular(", stringify!($type), "::<0, 59>::new_static::<50>());")]
+            #[doc = concat!("let end = Modular(", stringify!($type), "::<0, 59>::new_static::<10>());")]
+            /// assert_eq!(start.distance_forward(end), 20);
+            /// assert_eq!(start.distance_backward(end), 40);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn distance_forward(self, other: Self) -> $unsigned_type {
+                static_assert!(MIN <= MAX);
+                let from = self.0.get();
+                let to = other.0.get();
+                if from <= to {
+                    to.abs_diff(from)
                 } else {
-                    // Safety: The value was previously checked for validity.
-                    Ok(unsafe { Self::new_unchecked(value) })
+                    // Won't overflow, as the difference is at least one.
+                    MAX.abs_diff(MIN) - (from.abs_diff(to) - 1)
                 }
             }
+
+            /// The number of steps needed to reach `other` by repeatedly decrementing `self`,
+            /// wrapping around the bounds.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub const fn distance_backward(self, other: Self) -> $unsigned_type {
+                static_assert!(MIN <= MAX);
+                other.distance_forward(self)
+            }
         }
 
         $(impl<
@@ -1490,7 +3139,13 @@ macro_rules! impl_ranged {
             #[inline(always)]
             #[allow(trivial_numeric_casts, unused_comparisons)]
             fn from(value: $from<MIN_SRC, MAX_SRC>) -> Self {
-                const {
+                /// Assert that every value in the source range is in the destination range.
+                const fn assert_subset<
+                    const MIN_SRC: $from_internal,
+                    const MAX_SRC: $from_internal,
+                    const MIN_DST: $internal,
+                    const MAX_DST: $internal,
+                >() {
                     assert!(MIN_SRC <= MAX_SRC, "source range is invalid");
                     assert!(MIN_DST <= MAX_DST, "target range is invalid");
 
@@ -1543,18 +3198,272 @@ macro_rules! impl_ranged {
                         }
                     }
                 }
+                const { assert_subset::<MIN_SRC, MAX_SRC, MIN_DST, MAX_DST>() };
 
                 // Safety: The source range is a subset of the destination range.
                 unsafe { $type::new_unchecked(value.get() as $internal) }
             }
         })+
 
+        impl<const MIN: $internal, const MAX: $internal> AllValues<$type<MIN, MAX>> {
+            /// The number of values remaining after the next one, or `None` if the iterator is
+            /// exhausted.
+            #[inline]
+            const fn remaining_after_next(&self) -> Option<$unsigned_type> {
+                if self.exhausted {
+                    None
+                } else {
+                    Some(self.back.get().abs_diff(self.front.get()))
+                }
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> Iterator for AllValues<$type<MIN, MAX>> {
+            type Item = $type<MIN, MAX>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.nth(0)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = match self.remaining_after_next() {
+                    Some(remaining) => usize::try_from(remaining)
+                        .ok()
+                        .and_then(|remaining| remaining.checked_add(1)),
+                    None => Some(0),
+                };
+                match len {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+
+            #[inline]
+            #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                static_assert!(MIN <= MAX);
+                let remaining = self.remaining_after_next()?;
+                let n = match $unsigned_type::try_from(n) {
+                    Ok(n) if n <= remaining => n,
+                    _ => {
+                        self.exhausted = true;
+                        return None;
+                    }
+                };
+                // Safety: `front + n <= back`, so the value is in range. The cast trick is the same
+                // as in `wrapping_add`.
+                let value = unsafe {
+                    $type::new_unchecked(
+                        ((self.front.get() as $unsigned_type).wrapping_add(n)) as $internal
+                    )
+                };
+                if n == remaining {
+                    self.exhausted = true;
+                } else {
+                    // Safety: `value < back`, so the next value is in range.
+                    self.front = unsafe { $type::new_unchecked(value.get() + 1) };
+                }
+                Some(value)
+            }
+
+            #[inline]
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> DoubleEndedIterator
+            for AllValues<$type<MIN, MAX>>
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.nth_back(0)
+            }
+
+            #[inline]
+            #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                static_assert!(MIN <= MAX);
+                let remaining = self.remaining_after_next()?;
+                let n = match $unsigned_type::try_from(n) {
+                    Ok(n) if n <= remaining => n,
+                    _ => {
+                        self.exhausted = true;
+                        return None;
+                    }
+                };
+                // Safety: `back - n >= front`, so the value is in range. The cast trick is the same
+                // as in `wrapping_sub`.
+                let value = unsafe {
+                    $type::new_unchecked(
+                        ((self.back.get() as $unsigned_type).wrapping_sub(n)) as $internal
+                    )
+                };
+                if n == remaining {
+                    self.exhausted = true;
+                } else {
+                    // Safety: `value > front`, so the previous value is in range.
+                    self.back = unsafe { $type::new_unchecked(value.get() - 1) };
+                }
+                Some(value)
+            }
+        }
+
+        /// Calling `len` **fails to compile** if the number of values in the range does not fit
+        /// in a `usize`.
+        impl<const MIN: $internal, const MAX: $internal> ExactSizeIterator
+            for AllValues<$type<MIN, MAX>>
+        {
+            /// Returns the exact remaining length of the iterator. **Fails to compile** if the
+            /// number of values in the range does not fit in a `usize`.
+            #[inline]
+            #[allow(trivial_numeric_casts)] // needed since some casts are to the same type
+            fn len(&self) -> usize {
+                static_assert!(MIN <= MAX);
+                static_assert!(
+                    (MAX.abs_diff(MIN) as u128) < usize::MAX as u128,
+                    "the number of values in the range does not fit in a `usize`"
+                );
+                self.size_hint().0
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> FusedIterator
+            for AllValues<$type<MIN, MAX>>
+        {}
+
+        impl<const MIN: $internal, const MAX: $internal> Sealed for $type<MIN, MAX> {}
+
+        impl<const MIN: $internal, const MAX: $internal> Dense for $type<MIN, MAX> {
+            #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
+            const LEN: Option<usize> = {
+                static_assert!(MIN <= MAX);
+                if MAX.abs_diff(MIN) as u128 >= usize::MAX as u128 {
+                    None
+                } else {
+                    Some(MAX.abs_diff(MIN) as usize + 1)
+                }
+            };
+
+            #[inline]
+            #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
+            fn to_index(self) -> usize {
+                static_assert!(MIN <= MAX);
+                self.get().abs_diff(MIN) as usize
+            }
+
+            #[inline]
+            #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
+            unsafe fn from_index_unchecked(index: usize) -> Self {
+                static_assert!(MIN <= MAX);
+                // The cast trick is the same as in `wrapping_add`.
+                let value =
+                    (MIN as $unsigned_type).wrapping_add(index as $unsigned_type) as $internal;
+                // Safety: The caller guarantees that `index < LEN`, so `MIN + index <= MAX`.
+                unsafe { Self::new_unchecked(value) }
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> Ranged for $type<MIN, MAX> {
+            type Primitive = $internal;
+
+            const MIN: Self = Self::MIN;
+            const MAX: Self = Self::MAX;
+
+            #[inline(always)]
+            fn get(self) -> $internal {
+                self.get()
+            }
+
+            #[inline]
+            fn new(value: $internal) -> Option<Self> {
+                Self::new(value)
+            }
+
+            #[inline]
+            fn new_saturating(value: $internal) -> Self {
+                Self::new_saturating(value)
+            }
+
+            #[inline]
+            fn checked_add(self, rhs: $internal) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+
+            #[inline]
+            fn checked_sub(self, rhs: $internal) -> Option<Self> {
+                self.checked_sub(rhs)
+            }
+
+            #[inline]
+            fn checked_mul(self, rhs: $internal) -> Option<Self> {
+                self.checked_mul(rhs)
+            }
+
+            #[inline]
+            fn checked_div(self, rhs: $internal) -> Option<Self> {
+                self.checked_div(rhs)
+            }
+
+            #[inline]
+            fn checked_rem(self, rhs: $internal) -> Option<Self> {
+                self.checked_rem(rhs)
+            }
+
+            #[inline]
+            fn checked_neg(self) -> Option<Self> {
+                self.checked_neg()
+            }
+
+            #[inline]
+            fn checked_pow(self, exp: u32) -> Option<Self> {
+                self.checked_pow(exp)
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> Sealed for $optional_type<MIN, MAX> {}
+
+        impl<const MIN: $internal, const MAX: $internal> OptionalRanged
+            for $optional_type<MIN, MAX>
+        {
+            type Ranged = $type<MIN, MAX>;
+
+            const NONE: Self = Self::None;
+
+            #[inline(always)]
+            fn some(value: $type<MIN, MAX>) -> Self {
+                Self::Some(value)
+            }
+
+            #[inline(always)]
+            fn get(self) -> Option<$type<MIN, MAX>> {
+                self.get()
+            }
+
+            #[inline(always)]
+            fn get_primitive(self) -> Option<$internal> {
+                self.get_primitive()
+            }
+
+            #[inline(always)]
+            fn is_none(&self) -> bool {
+                self.is_none()
+            }
+
+            #[inline(always)]
+            fn is_some(&self) -> bool {
+                self.is_some()
+            }
+        }
+
         #[cfg(feature = "serde")]
         impl<const MIN: $internal, const MAX: $internal> serde_core::Serialize for $type<MIN, MAX> {
             #[inline(always)]
             fn serialize<S: serde_core::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
             {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().serialize(serializer)
             }
         }
@@ -1567,7 +3476,7 @@ macro_rules! impl_ranged {
             #[inline(always)]
             fn serialize<S: serde_core::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
             {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 self.get().serialize(serializer)
             }
         }
@@ -1582,7 +3491,7 @@ macro_rules! impl_ranged {
             fn deserialize<D: serde_core::Deserializer<'de>>(deserializer: D)
                 -> Result<Self, D::Error>
             {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 let internal = <$internal>::deserialize(deserializer)?;
                 Self::new(internal).ok_or_else(||
                     <D::Error as serde_core::de::Error>::invalid_value(
@@ -1608,8 +3517,8 @@ macro_rules! impl_ranged {
             fn deserialize<D: serde_core::Deserializer<'de>>(deserializer: D)
                 -> Result<Self, D::Error>
             {
-                const { assert!(MIN <= MAX); }
-                Ok(Self::Some($type::<MIN, MAX>::deserialize(deserializer)?))
+                static_assert!(MIN <= MAX);
+                Ok(Option::<$type<MIN, MAX>>::deserialize(deserializer)?.into())
             }
         }
 
@@ -1620,7 +3529,7 @@ macro_rules! impl_ranged {
         > rand08::distributions::Distribution<$type<MIN, MAX>> for rand08::distributions::Standard {
             #[inline]
             fn sample<R: rand08::Rng + ?Sized>(&self, rng: &mut R) -> $type<MIN, MAX> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 $type::new(rng.gen_range(MIN..=MAX)).expect("rand failed to generate a valid value")
             }
         }
@@ -1634,7 +3543,7 @@ macro_rules! impl_ranged {
             > rand09::distr::Distribution<$type<MIN, MAX>> for rand09::distr::StandardUniform {
                 #[inline]
                 fn sample<R: rand09::Rng + ?Sized>(&self, rng: &mut R) -> $type<MIN, MAX> {
-                    const { assert!(MIN <= MAX); }
+                    static_assert!(MIN <= MAX);
                     $type::new(rng.random_range(MIN..=MAX)).expect("rand failed to generate a valid value")
                 }
             }
@@ -1649,7 +3558,7 @@ macro_rules! impl_ranged {
             > rand010::distr::Distribution<$type<MIN, MAX>> for rand010::distr::StandardUniform {
                 #[inline]
                 fn sample<R: rand010::Rng + ?Sized>(&self, rng: &mut R) -> $type<MIN, MAX> {
-                    const { assert!(MIN <= MAX); }
+                    static_assert!(MIN <= MAX);
                     use rand010::RngExt as _;
                     $type::new(rng.random_range(MIN..=MAX)).expect("rand failed to generate a valid value")
                 }
@@ -1664,7 +3573,7 @@ macro_rules! impl_ranged {
         for rand08::distributions::Standard {
             #[inline]
             fn sample<R: rand08::Rng + ?Sized>(&self, rng: &mut R) -> $optional_type<MIN, MAX> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 rng.r#gen::<Option<$type<MIN, MAX>>>().into()
             }
         }
@@ -1677,7 +3586,7 @@ macro_rules! impl_ranged {
         for rand09::distr::StandardUniform {
             #[inline]
             fn sample<R: rand09::Rng + ?Sized>(&self, rng: &mut R) -> $optional_type<MIN, MAX> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 if rng.random() {
                     $optional_type::None
                 } else {
@@ -1694,7 +3603,7 @@ macro_rules! impl_ranged {
         for rand010::distr::StandardUniform {
             #[inline]
             fn sample<R: rand010::Rng + ?Sized>(&self, rng: &mut R) -> $optional_type<MIN, MAX> {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 use rand010::RngExt as _;
                 if rng.random() {
                     $optional_type::None
@@ -1704,17 +3613,60 @@ macro_rules! impl_ranged {
             }
         }
 
+        #[cfg(feature = "unstable-step-trait")]
+        impl<const MIN: $internal, const MAX: $internal> core::iter::Step for $type<MIN, MAX> {
+            #[inline]
+            fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+                static_assert!(MIN <= MAX);
+                if start > end {
+                    return (0, None);
+                }
+                match usize::try_from(end.get().abs_diff(start.get())) {
+                    Ok(steps) => (steps, Some(steps)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+
+            #[inline]
+            #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
+            fn forward_checked(start: Self, count: usize) -> Option<Self> {
+                static_assert!(MIN <= MAX);
+                let count = $unsigned_type::try_from(count).ok()?;
+                if count > MAX.abs_diff(start.get()) {
+                    return None;
+                }
+                // The cast trick is the same as in `wrapping_add`.
+                let value = (start.get() as $unsigned_type).wrapping_add(count) as $internal;
+                // Safety: `start + count <= MAX`, so the value is in range.
+                Some(unsafe { Self::new_unchecked(value) })
+            }
+
+            #[inline]
+            #[allow(trivial_numeric_casts)] // needed since some casts have to send unsigned -> unsigned to handle signed -> unsigned
+            fn backward_checked(start: Self, count: usize) -> Option<Self> {
+                static_assert!(MIN <= MAX);
+                let count = $unsigned_type::try_from(count).ok()?;
+                if count > start.get().abs_diff(MIN) {
+                    return None;
+                }
+                // The cast trick is the same as in `wrapping_sub`.
+                let value = (start.get() as $unsigned_type).wrapping_sub(count) as $internal;
+                // Safety: `start - count >= MIN`, so the value is in range.
+                Some(unsafe { Self::new_unchecked(value) })
+            }
+        }
+
         #[cfg(feature = "num")]
         impl<const MIN: $internal, const MAX: $internal> num_traits::Bounded for $type<MIN, MAX> {
             #[inline(always)]
             fn min_value() -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::MIN
             }
 
             #[inline(always)]
             fn max_value() -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Self::MAX
             }
         }
@@ -1723,7 +3675,7 @@ macro_rules! impl_ranged {
         impl<const MIN: $internal, const MAX: $internal> quickcheck::Arbitrary for $type<MIN, MAX> {
             #[inline]
             fn arbitrary(g: &mut quickcheck::Gen) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 // Safety: The `rem_euclid` call and addition ensure that the value is in range.
                 unsafe {
                     Self::new_unchecked($internal::arbitrary(g).rem_euclid(MAX - MIN + 1) + MIN)
@@ -1747,7 +3699,7 @@ macro_rules! impl_ranged {
         > quickcheck::Arbitrary for $optional_type<MIN, MAX> {
             #[inline]
             fn arbitrary(g: &mut quickcheck::Gen) -> Self {
-                const { assert!(MIN <= MAX); }
+                static_assert!(MIN <= MAX);
                 Option::<$type<MIN, MAX>>::arbitrary(g).into()
             }
 
@@ -1781,6 +3733,7 @@ impl_ranged! {
             RangedI128(i128)
             RangedIsize(isize)
         ]
+        widening: RangedU16(u16)
     }
     RangedU16 {
         mod_name: ranged_u16
@@ -1803,6 +3756,7 @@ impl_ranged! {
             RangedI128(i128)
             RangedIsize(isize)
         ]
+        widening: RangedU32(u32)
     }
     RangedU32 {
         mod_name: ranged_u32
@@ -1825,6 +3779,7 @@ impl_ranged! {
             RangedI128(i128)
             RangedIsize(isize)
         ]
+        widening: RangedU64(u64)
     }
     RangedU64 {
         mod_name: ranged_u64
@@ -1847,6 +3802,7 @@ impl_ranged! {
             RangedI128(i128)
             RangedIsize(isize)
         ]
+        widening: RangedU128(u128)
     }
     RangedU128 {
         mod_name: ranged_u128
@@ -1891,6 +3847,7 @@ impl_ranged! {
             RangedI128(i128)
             RangedIsize(isize)
         ]
+        widening: RangedU128(u128)
         manual: [rand_09 rand_010]
     }
     RangedI8 {
@@ -1914,6 +3871,7 @@ impl_ranged! {
             RangedI128(i128)
             RangedIsize(isize)
         ]
+        widening: RangedI16(i16)
     }
     RangedI16 {
         mod_name: ranged_i16
@@ -1936,6 +3894,7 @@ impl_ranged! {
             RangedI128(i128)
             RangedIsize(isize)
         ]
+        widening: RangedI32(i32)
     }
     RangedI32 {
         mod_name: ranged_i32
@@ -1958,6 +3917,7 @@ impl_ranged! {
             RangedI128(i128)
             RangedIsize(isize)
         ]
+        widening: RangedI64(i64)
     }
     RangedI64 {
         mod_name: ranged_i64
@@ -1980,6 +3940,7 @@ impl_ranged! {
             RangedI128(i128)
             RangedIsize(isize)
         ]
+        widening: RangedI128(i128)
     }
     RangedI128 {
         mod_name: ranged_i128
@@ -2024,6 +3985,7 @@ impl_ranged! {
             RangedI64(i64)
             RangedI128(i128)
         ]
+        widening: RangedI128(i128)
         manual: [rand_09 rand_010]
     }
 }
@@ -2034,9 +3996,7 @@ impl<const MIN: usize, const MAX: usize> rand09::distr::Distribution<RangedUsize
 {
     #[inline]
     fn sample<R: rand09::Rng + ?Sized>(&self, rng: &mut R) -> RangedUsize<MIN, MAX> {
-        const {
-            assert!(MIN <= MAX);
-        }
+        static_assert!(MIN <= MAX);
 
         #[cfg(target_pointer_width = "16")]
         let value = rng.random_range(MIN as u16..=MAX as u16) as usize;
@@ -2061,9 +4021,7 @@ impl<const MIN: isize, const MAX: isize> rand09::distr::Distribution<RangedIsize
 {
     #[inline]
     fn sample<R: rand09::Rng + ?Sized>(&self, rng: &mut R) -> RangedIsize<MIN, MAX> {
-        const {
-            assert!(MIN <= MAX);
-        }
+        static_assert!(MIN <= MAX);
 
         #[cfg(target_pointer_width = "16")]
         let value = rng.random_range(MIN as i16..=MAX as i16) as isize;
@@ -2088,9 +4046,7 @@ impl<const MIN: usize, const MAX: usize> rand010::distr::Distribution<RangedUsiz
 {
     #[inline]
     fn sample<R: rand010::Rng + ?Sized>(&self, rng: &mut R) -> RangedUsize<MIN, MAX> {
-        const {
-            assert!(MIN <= MAX);
-        }
+        static_assert!(MIN <= MAX);
 
         use rand010::RngExt as _;
 
@@ -2117,9 +4073,7 @@ impl<const MIN: isize, const MAX: isize> rand010::distr::Distribution<RangedIsiz
 {
     #[inline]
     fn sample<R: rand010::Rng + ?Sized>(&self, rng: &mut R) -> RangedIsize<MIN, MAX> {
-        const {
-            assert!(MIN <= MAX);
-        }
+        static_assert!(MIN <= MAX);
 
         use rand010::RngExt as _;
 
@@ -0,0 +1,440 @@
+//! A map whose keys are ranged integers, stored densely in an array.
+//!
+//! See [`RangedMap`] for details.
+
+use core::iter::{Enumerate, FusedIterator};
+use core::marker::PhantomData;
+use core::{array, fmt, slice};
+
+use crate::traits::Dense;
+use crate::Ranged;
+
+/// Asserts at compile time that `N` is the number of values of the ranged integer type `K`.
+macro_rules! assert_len {
+    ($key:ident, $len:ident) => {
+        static_assert!(
+            has_len::<$key>($len),
+            "the length of the map must be the number of values in the range of the key",
+        );
+    };
+}
+
+/// Returns `true` if `len` is the number of values of the ranged integer type `K`.
+const fn has_len<K: Dense>(len: usize) -> bool {
+    matches!(K::LEN, Some(key_len) if key_len == len)
+}
+
+/// A map whose keys are every value of a ranged integer type.
+///
+/// Values are stored inline in an array with one slot for each possible key, so no hashing takes
+/// place and lookups cannot be out of bounds. `N` must be the number of values in the range of the
+/// key, which is checked at compile time. For a key of `RangedU8<1, 12>`, `N` is 12.
+///
+/// # Example
+///
+/// ```rust
+/// # use deranged::{RangedMap, RangedU8};
+/// type Hour = RangedU8<0, 23>;
+///
+/// let mut visitors = RangedMap::<Hour, u32, 24>::new();
+/// visitors.insert(Hour::new_static::<9>(), 15);
+/// visitors.insert(Hour::new_static::<17>(), 40);
+/// *visitors.entry(Hour::new_static::<9>()).or_insert(0) += 1;
+///
+/// assert_eq!(visitors.get(Hour::new_static::<9>()), Some(&16));
+/// assert_eq!(visitors.get(Hour::new_static::<10>()), None);
+/// assert_eq!(visitors.len(), 2);
+/// ```
+///
+/// Using a length that does not match the range of the key fails to compile.
+///
+/// ```rust,compile_fail
+/// # use deranged::{RangedMap, RangedU8};
+/// let map = RangedMap::<RangedU8<0, 23>, u32, 12>::new();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangedMap<K, V, const N: usize> {
+    /// The value for each key, indexed by the distance of the key from its minimum.
+    values: [Option<V>; N],
+    /// The type of the key.
+    key: PhantomData<K>,
+}
+
+impl<K: Ranged, V, const N: usize> RangedMap<K, V, N> {
+    /// The value of a key that is not in the map. Unlike `const { None }`, this may be used in an
+    /// array repeat expression when the `unstable-generic-const-exprs` feature is enabled.
+    const NONE: Option<V> = None;
+
+    /// Creates an empty map.
+    #[inline]
+    pub const fn new() -> Self {
+        assert_len!(K, N);
+        Self {
+            values: [Self::NONE; N],
+            key: PhantomData,
+        }
+    }
+
+    /// Creates a map containing every key, with each value computed by calling `f` with its key.
+    /// Keys are passed in ascending order.
+    #[inline]
+    pub fn from_fn<F: FnMut(K) -> V>(mut f: F) -> Self {
+        assert_len!(K, N);
+        Self {
+            // Safety: `index < N`, which is the number of values of `K`.
+            values: array::from_fn(|index| Some(f(unsafe { K::from_index_unchecked(index) }))),
+            key: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the value for the key, if present.
+    #[inline]
+    pub fn get(&self, key: K) -> Option<&V> {
+        self.slot(key).as_ref()
+    }
+
+    /// Returns a mutable reference to the value for the key, if present.
+    #[inline]
+    pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        self.slot_mut(key).as_mut()
+    }
+
+    /// Returns `true` if the map contains a value for the key.
+    #[inline]
+    pub fn contains_key(&self, key: K) -> bool {
+        self.slot(key).is_some()
+    }
+
+    /// Inserts a value for the key, returning the previous value if present.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.slot_mut(key).replace(value)
+    }
+
+    /// Removes the value for the key, returning it if present.
+    #[inline]
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        self.slot_mut(key).take()
+    }
+
+    /// Returns the entry for the key, permitting in-place manipulation.
+    #[inline]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        Entry {
+            key,
+            slot: self.slot_mut(key),
+        }
+    }
+
+    /// Returns the number of keys with a value. This takes time proportional to `N`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.iter().filter(|value| value.is_some()).count()
+    }
+
+    /// Returns `true` if no key has a value. This takes time proportional to `N`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.iter().all(Option::is_none)
+    }
+
+    /// Removes every value from the map.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.values = [Self::NONE; N];
+    }
+
+    /// Returns an iterator over the keys with a value and references to those values, in
+    /// ascending order of key.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.values.iter().enumerate(),
+            key: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the keys with a value and mutable references to those values,
+    /// in ascending order of key.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self.values.iter_mut().enumerate(),
+            key: PhantomData,
+        }
+    }
+
+    /// Creates a map with the same keys, with each value replaced by the result of calling `f`
+    /// with its key and value.
+    #[inline]
+    pub fn map<W, F: FnMut(K, V) -> W>(self, mut f: F) -> RangedMap<K, W, N> {
+        assert_len!(K, N);
+        let mut index = 0;
+        RangedMap {
+            values: self.values.map(|value| {
+                // Safety: `index < N`, which is the number of values of `K`.
+                let key = unsafe { K::from_index_unchecked(index) };
+                index += 1;
+                value.map(|value| f(key, value))
+            }),
+            key: PhantomData,
+        }
+    }
+
+    /// Returns the slot for the key.
+    #[inline]
+    fn slot(&self, key: K) -> &Option<V> {
+        assert_len!(K, N);
+        // Safety: The index of the key is less than the number of values of `K`, which is `N`.
+        unsafe { self.values.get_unchecked(key.to_index()) }
+    }
+
+    /// Returns the slot for the key mutably.
+    #[inline]
+    fn slot_mut(&mut self, key: K) -> &mut Option<V> {
+        assert_len!(K, N);
+        // Safety: The index of the key is less than the number of values of `K`, which is `N`.
+        unsafe { self.values.get_unchecked_mut(key.to_index()) }
+    }
+}
+
+impl<K: Ranged, V, const N: usize> Default for RangedMap<K, V, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ranged, V: fmt::Debug, const N: usize> fmt::Debug for RangedMap<K, V, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ranged, V, const N: usize> FromIterator<(K, V)> for RangedMap<K, V, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ranged, V, const N: usize> Extend<(K, V)> for RangedMap<K, V, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K: Ranged, V, const N: usize> IntoIterator for &'a RangedMap<K, V, N> {
+    type Item = (K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: Ranged, V, const N: usize> IntoIterator for &'a mut RangedMap<K, V, N> {
+    type Item = (K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: Ranged, V, const N: usize> IntoIterator for RangedMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.values.into_iter().enumerate(),
+            key: PhantomData,
+        }
+    }
+}
+
+/// A view into a single slot of a [`RangedMap`], which may or may not have a value.
+///
+/// This struct is created by [`RangedMap::entry`].
+#[derive(Debug)]
+pub struct Entry<'a, K, V> {
+    /// The key of the slot.
+    key: K,
+    /// The value of the slot.
+    slot: &'a mut Option<V>,
+}
+
+impl<'a, K: Ranged, V> Entry<'a, K, V> {
+    /// Returns the key of the entry.
+    #[inline]
+    pub const fn key(&self) -> K {
+        self.key
+    }
+
+    /// Returns a mutable reference to the value, inserting `default` if there is none.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.slot.get_or_insert(default)
+    }
+
+    /// Returns a mutable reference to the value, inserting the result of `default` if there is
+    /// none.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        self.slot.get_or_insert_with(default)
+    }
+
+    /// Returns a mutable reference to the value, inserting the result of calling `default` with
+    /// the key if there is none.
+    #[inline]
+    pub fn or_insert_with_key<F: FnOnce(K) -> V>(self, default: F) -> &'a mut V {
+        let key = self.key;
+        self.slot.get_or_insert_with(|| default(key))
+    }
+
+    /// Returns a mutable reference to the value, inserting the default value if there is none.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.slot.get_or_insert_with(V::default)
+    }
+
+    /// Calls `f` with a mutable reference to the value if there is one.
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        if let Some(value) = self.slot.as_mut() {
+            f(value);
+        }
+        self
+    }
+
+    /// Returns a reference to the value if there is one.
+    #[inline]
+    pub const fn get(&self) -> Option<&V> {
+        self.slot.as_ref()
+    }
+
+    /// Inserts a value, returning the previous value if there was one.
+    #[inline]
+    pub const fn insert(&mut self, value: V) -> Option<V> {
+        self.slot.replace(value)
+    }
+
+    /// Removes the value, returning it if there was one.
+    #[inline]
+    pub const fn remove(self) -> Option<V> {
+        self.slot.take()
+    }
+}
+
+/// Implements the iterator traits for an iterator over a [`RangedMap`].
+macro_rules! impl_iterator {
+    ([$($generics:tt)*] $name:ty => $value:ty) => {
+        impl<$($generics)*> Iterator for $name
+        where
+            K: Ranged,
+        {
+            type Item = (K, $value);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.find_map(|(index, value)| {
+                    let value = Option::<$value>::from(value)?;
+                    // Safety: `index` is less than the length of the map, which is the number of
+                    // values of `K`.
+                    Some((unsafe { K::from_index_unchecked(index) }, value))
+                })
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (0, self.inner.size_hint().1)
+            }
+        }
+
+        #[allow(single_use_lifetimes)] // the lifetime cannot be elided in the macro input
+        impl<$($generics)*> DoubleEndedIterator for $name
+        where
+            K: Ranged,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                while let Some((index, value)) = self.inner.next_back() {
+                    if let Some(value) = Option::<$value>::from(value) {
+                        // Safety: `index` is less than the length of the map, which is the number
+                        // of values of `K`.
+                        return Some((unsafe { K::from_index_unchecked(index) }, value));
+                    }
+                }
+                None
+            }
+        }
+
+        #[allow(single_use_lifetimes)] // the lifetime cannot be elided in the macro input
+        impl<$($generics)*> FusedIterator for $name where K: Ranged {}
+    };
+}
+
+/// An iterator over the keys with a value and references to those values.
+///
+/// This struct is created by [`RangedMap::iter`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct Iter<'a, K, V> {
+    /// The slots that have not been visited.
+    inner: Enumerate<slice::Iter<'a, Option<V>>>,
+    /// The type of the key.
+    key: PhantomData<K>,
+}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            key: PhantomData,
+        }
+    }
+}
+
+impl_iterator!(['a, K, V] Iter<'a, K, V> => &'a V);
+
+/// An iterator over the keys with a value and mutable references to those values.
+///
+/// This struct is created by [`RangedMap::iter_mut`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct IterMut<'a, K, V> {
+    /// The slots that have not been visited.
+    inner: Enumerate<slice::IterMut<'a, Option<V>>>,
+    /// The type of the key.
+    key: PhantomData<K>,
+}
+
+impl_iterator!(['a, K, V] IterMut<'a, K, V> => &'a mut V);
+
+/// An owning iterator over the keys with a value and those values.
+///
+/// This struct is created by the `into_iter` method on [`RangedMap`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct IntoIter<K, V, const N: usize> {
+    /// The slots that have not been visited.
+    inner: Enumerate<array::IntoIter<Option<V>, N>>,
+    /// The type of the key.
+    key: PhantomData<K>,
+}
+
+impl_iterator!([K, V, const N: usize] IntoIter<K, V, N> => V);
@@ -0,0 +1,34 @@
+//! Declaration of `Modular`, which provides wrapping arithmetic for ranged integers.
+
+use core::fmt;
+
+/// Intentionally-wrapped arithmetic on a ranged integer.
+///
+/// Operators wrap around the bounds of the inner type, as the hands of a clock do:
+/// `Modular<RangedU8<0, 59>>` behaves like a minute hand, so adding one to 59 results in 0.
+///
+/// # Examples
+///
+/// ```rust
+/// # use deranged::{Modular, RangedU8};
+/// let minute = Modular(RangedU8::<0, 59>::new_static::<45>());
+/// assert_eq!((minute + 20).0.get(), 5);
+/// assert_eq!((minute - 50).0.get(), 55);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Modular<T>(pub T);
+
+impl<T: fmt::Debug> fmt::Debug for Modular<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Modular<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,529 @@
+//! Declaration of `ranged_newtype!`, which defines a newtype over a ranged integer.
+
+use core::error::Error;
+use core::fmt;
+
+/// Define a newtype over a ranged integer.
+///
+/// The newtype wraps the ranged integer whose inner type and bounds are provided, written as
+/// `primitive in MIN..=MAX`. It provides the following:
+///
+/// - `MIN` and `MAX` constants
+/// - `new`, `new_static`, `get`, `from_ranged`, and `to_ranged` methods
+/// - `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, and `Hash`, which are derived
+/// - `Debug`, `Display`, and `FromStr`
+/// - `TryFrom` the primitive type and `From` the ranged integer type
+/// - `From` the newtype to the primitive and ranged integer types
+/// - the `serde`, `rand`, `num`, and `quickcheck` implementations that the ranged integer type has,
+///   when the corresponding features of this crate are enabled
+///
+/// Any attributes, including doc comments, are applied to the newtype. The derives listed above are
+/// always applied, so they must not be repeated; doing so results in conflicting implementations.
+///
+/// Optionally, an `error` message and a display `suffix` may be provided, in that order, in a
+/// braced block following the type. The message is used by the [`NewtypeError`] returned when
+/// conversion or parsing fails, as well as by deserialization. The suffix is written after the
+/// value by `Display` and is permitted, but not required, when parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// deranged::ranged_newtype! {
+///     /// A percentage.
+///     pub struct Percent(u8 in 0..=100) {
+///         error: "a percentage must be between 0 and 100",
+///         suffix: "%",
+///     }
+/// }
+///
+/// let percent = Percent::new_static::<42>();
+/// assert_eq!(percent.to_string(), "42%");
+/// assert_eq!("42%".parse::<Percent>(), Ok(percent));
+/// assert_eq!("42".parse::<Percent>(), Ok(percent));
+/// assert_eq!(Percent::new(101), None);
+/// assert_eq!(
+///     Percent::try_from(101).unwrap_err().to_string(),
+///     "a percentage must be between 0 and 100"
+/// );
+/// ```
+///
+/// ```rust
+/// deranged::ranged_newtype! {
+///     /// A UTC offset in minutes.
+///     struct Offset(i16 in -720..=840);
+/// }
+///
+/// assert_eq!(Offset::MIN.get(), -720);
+/// assert_eq!(
+///     Offset::try_from(900).unwrap_err().to_string(),
+///     "900 is not in the range -720..=840"
+/// );
+/// ```
+#[macro_export]
+macro_rules! ranged_newtype {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($primitive:ident in $min:literal..=$max:literal)
+        $({
+            $(error: $error:literal)? $(,)?
+            $(suffix: $suffix:literal)? $(,)?
+        })?
+        $(;)?
+    ) => {
+        $crate::ranged_newtype! {
+            @with_type $primitive;
+            $(#[$attr])*
+            $vis struct $name($min, $max) [$($($error)?)?] [$($($suffix)?)?]
+        }
+    };
+
+    (@with_type u8; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedU8 u8; $($rest)*);
+    };
+    (@with_type u16; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedU16 u16; $($rest)*);
+    };
+    (@with_type u32; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedU32 u32; $($rest)*);
+    };
+    (@with_type u64; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedU64 u64; $($rest)*);
+    };
+    (@with_type u128; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedU128 u128; $($rest)*);
+    };
+    (@with_type usize; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedUsize usize; $($rest)*);
+    };
+    (@with_type i8; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedI8 i8; $($rest)*);
+    };
+    (@with_type i16; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedI16 i16; $($rest)*);
+    };
+    (@with_type i32; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedI32 i32; $($rest)*);
+    };
+    (@with_type i64; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedI64 i64; $($rest)*);
+    };
+    (@with_type i128; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedI128 i128; $($rest)*);
+    };
+    (@with_type isize; $($rest:tt)*) => {
+        $crate::ranged_newtype!(@define RangedIsize isize; $($rest)*);
+    };
+
+    (
+        @define $ranged:ident $primitive:ident;
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($min:literal, $max:literal)
+        [$($error:literal)?] [$($suffix:literal)?]
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        $vis struct $name($crate::$ranged<$min, $max>);
+
+        #[allow(dead_code)]
+        impl $name {
+            /// The smallest value that can be represented by this type.
+            $vis const MIN: Self = Self($crate::$ranged::<$min, $max>::MIN);
+
+            /// The largest value that can be represented by this type.
+            $vis const MAX: Self = Self($crate::$ranged::<$min, $max>::MAX);
+
+            /// The message used by errors, if a custom message was provided.
+            const ERROR_MESSAGE: ::core::option::Option<&str> =
+                $crate::ranged_newtype!(@message $($error)?);
+
+            /// The suffix written after the value when displaying it.
+            const SUFFIX: &str = $crate::ranged_newtype!(@suffix $($suffix)?);
+
+            /// Creates a value if it is in range.
+            #[inline]
+            $vis const fn new(value: $primitive) -> ::core::option::Option<Self> {
+                match $crate::$ranged::<$min, $max>::new(value) {
+                    ::core::option::Option::Some(value) => {
+                        ::core::option::Option::Some(Self(value))
+                    }
+                    ::core::option::Option::None => ::core::option::Option::None,
+                }
+            }
+
+            /// Creates a value that is known to be in range at compile time.
+            #[inline]
+            $vis const fn new_static<const VALUE: $primitive>() -> Self {
+                Self($crate::$ranged::<$min, $max>::new_static::<VALUE>())
+            }
+
+            /// Returns the value as a primitive type.
+            #[inline]
+            $vis const fn get(self) -> $primitive {
+                self.0.get()
+            }
+
+            /// Creates a value from the ranged integer it wraps.
+            #[inline]
+            $vis const fn from_ranged(
+                value: $crate::$ranged<$min, $max>,
+            ) -> Self {
+                Self(value)
+            }
+
+            /// Returns the ranged integer that is wrapped.
+            #[inline]
+            $vis const fn to_ranged(self) -> $crate::$ranged<$min, $max> {
+                self.0
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(::core::stringify!($name)).field(&self.get()).finish()
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)?;
+                f.write_str(Self::SUFFIX)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::NewtypeError<$crate::ParseIntError>;
+
+            #[inline]
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                let s = s.strip_suffix(Self::SUFFIX).unwrap_or(s);
+                s.parse()
+                    .map(Self)
+                    .map_err(|error| $crate::NewtypeError::new(error, Self::ERROR_MESSAGE))
+            }
+        }
+
+        impl ::core::convert::TryFrom<$primitive> for $name {
+            type Error = $crate::NewtypeError<$crate::TryFromIntError>;
+
+            #[inline]
+            fn try_from(value: $primitive) -> ::core::result::Result<Self, Self::Error> {
+                ::core::convert::TryFrom::try_from(value)
+                    .map(Self)
+                    .map_err(|error| $crate::NewtypeError::new(error, Self::ERROR_MESSAGE))
+            }
+        }
+
+        impl ::core::convert::From<$crate::$ranged<$min, $max>>
+            for $name
+        {
+            #[inline]
+            fn from(value: $crate::$ranged<$min, $max>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::core::convert::From<$name>
+            for $crate::$ranged<$min, $max>
+        {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::convert::From<$name> for $primitive {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        $crate::__ranged_newtype_serde! {
+            impl $crate::__private::serde_core::Serialize for $name {
+                #[inline]
+                fn serialize<S: $crate::__private::serde_core::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> ::core::result::Result<S::Ok, S::Error> {
+                    $crate::__private::serde_core::Serialize::serialize(&self.0, serializer)
+                }
+            }
+
+            impl<'de> $crate::__private::serde_core::Deserialize<'de> for $name {
+                #[inline]
+                fn deserialize<D: $crate::__private::serde_core::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::core::result::Result<Self, D::Error> {
+                    let ::core::option::Option::Some(message) = Self::ERROR_MESSAGE else {
+                        return $crate::__private::serde_core::Deserialize::deserialize(
+                            deserializer,
+                        )
+                        .map(Self);
+                    };
+                    let value: $primitive =
+                        $crate::__private::serde_core::Deserialize::deserialize(deserializer)?;
+                    Self::new(value).ok_or_else(|| {
+                        <D::Error as $crate::__private::serde_core::de::Error>::custom(message)
+                    })
+                }
+            }
+        }
+
+        $crate::__ranged_newtype_rand08! {
+            impl $crate::__private::rand08::distributions::Distribution<$name>
+                for $crate::__private::rand08::distributions::Standard
+            {
+                #[inline]
+                fn sample<R: $crate::__private::rand08::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                    $name($crate::__private::rand08::distributions::Distribution::sample(self, rng))
+                }
+            }
+        }
+
+        $crate::__ranged_newtype_rand09! {
+            impl $crate::__private::rand09::distr::Distribution<$name>
+                for $crate::__private::rand09::distr::StandardUniform
+            {
+                #[inline]
+                fn sample<R: $crate::__private::rand09::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                    $name($crate::__private::rand09::distr::Distribution::sample(self, rng))
+                }
+            }
+        }
+
+        $crate::__ranged_newtype_rand010! {
+            impl $crate::__private::rand010::distr::Distribution<$name>
+                for $crate::__private::rand010::distr::StandardUniform
+            {
+                #[inline]
+                fn sample<R: $crate::__private::rand010::Rng + ?Sized>(
+                    &self,
+                    rng: &mut R,
+                ) -> $name {
+                    $name($crate::__private::rand010::distr::Distribution::sample(self, rng))
+                }
+            }
+        }
+
+        $crate::__ranged_newtype_num! {
+            impl $crate::__private::num_traits::Bounded for $name {
+                #[inline]
+                fn min_value() -> Self {
+                    Self::MIN
+                }
+
+                #[inline]
+                fn max_value() -> Self {
+                    Self::MAX
+                }
+            }
+        }
+
+        $crate::__ranged_newtype_quickcheck! {
+            impl $crate::__private::quickcheck::Arbitrary for $name {
+                #[inline]
+                fn arbitrary(g: &mut $crate::__private::quickcheck::Gen) -> Self {
+                    Self($crate::__private::quickcheck::Arbitrary::arbitrary(g))
+                }
+
+                #[inline]
+                fn shrink(
+                    &self,
+                ) -> $crate::__private::Box<dyn ::core::iter::Iterator<Item = Self>>
+                {
+                    $crate::__private::Box::new(
+                        $crate::__private::quickcheck::Arbitrary::shrink(&self.0).map(Self),
+                    )
+                }
+            }
+        }
+    };
+
+    (@message $error:literal) => { ::core::option::Option::Some($error) };
+    (@message) => { ::core::option::Option::None };
+
+    (@suffix $suffix:literal) => { $suffix };
+    (@suffix) => { "" };
+}
+
+/// Outputs the provided code if and only if the `serde` feature is enabled.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_serde {
+    ($($output:tt)*) => { $($output)* };
+}
+
+/// Outputs the provided code if and only if the `serde` feature is enabled.
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_serde {
+    ($($output:tt)*) => {};
+}
+
+/// Outputs the provided code if and only if the `rand08` feature is enabled.
+#[cfg(feature = "rand08")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_rand08 {
+    ($($output:tt)*) => { $($output)* };
+}
+
+/// Outputs the provided code if and only if the `rand08` feature is enabled.
+#[cfg(not(feature = "rand08"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_rand08 {
+    ($($output:tt)*) => {};
+}
+
+/// Outputs the provided code if and only if the `rand09` feature is enabled.
+#[cfg(feature = "rand09")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_rand09 {
+    ($($output:tt)*) => { $($output)* };
+}
+
+/// Outputs the provided code if and only if the `rand09` feature is enabled.
+#[cfg(not(feature = "rand09"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_rand09 {
+    ($($output:tt)*) => {};
+}
+
+/// Outputs the provided code if and only if the `rand010` feature is enabled.
+#[cfg(feature = "rand010")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_rand010 {
+    ($($output:tt)*) => { $($output)* };
+}
+
+/// Outputs the provided code if and only if the `rand010` feature is enabled.
+#[cfg(not(feature = "rand010"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_rand010 {
+    ($($output:tt)*) => {};
+}
+
+/// Outputs the provided code if and only if the `num` feature is enabled.
+#[cfg(feature = "num")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_num {
+    ($($output:tt)*) => { $($output)* };
+}
+
+/// Outputs the provided code if and only if the `num` feature is enabled.
+#[cfg(not(feature = "num"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_num {
+    ($($output:tt)*) => {};
+}
+
+/// Outputs the provided code if and only if the `quickcheck` feature is enabled.
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_quickcheck {
+    ($($output:tt)*) => { $($output)* };
+}
+
+/// Outputs the provided code if and only if the `quickcheck` feature is enabled.
+#[cfg(not(feature = "quickcheck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ranged_newtype_quickcheck {
+    ($($output:tt)*) => {};
+}
+
+/// An error returned when a value of a type defined by [`ranged_newtype!`] cannot be created.
+///
+/// If a custom message was provided when defining the type, it is used when displaying the error.
+/// Otherwise the underlying error is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NewtypeError<E> {
+    /// The error returned by the ranged integer type.
+    error: E,
+    /// The custom message, if any.
+    message: Option<&'static str>,
+}
+
+impl<E> NewtypeError<E> {
+    /// Creates an error from the underlying error and a custom message.
+    #[inline]
+    pub const fn new(error: E, message: Option<&'static str>) -> Self {
+        Self { error, message }
+    }
+
+    /// Returns the error returned by the ranged integer type.
+    #[inline]
+    pub const fn inner(&self) -> &E {
+        &self.error
+    }
+
+    /// Consumes the error, returning the error returned by the ranged integer type.
+    #[inline]
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+
+    /// Returns the custom message, if one was provided.
+    #[inline]
+    pub const fn message(&self) -> Option<&'static str> {
+        self.message
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for NewtypeError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.message {
+            Some(message) => f.write_str(message),
+            None => self.error.fmt(f),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for NewtypeError<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.message {
+            Some(_) => Some(&self.error),
+            None => None,
+        }
+    }
+}
+
+/// Items used by macros and in the signatures of generic items. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    /// Bounds of the results of arithmetic, which appear in the output types of operators.
+    pub mod bounds {
+        pub use crate::bounds::{i128, i16, i32, i64, i8, isize, u128, u16, u32, u64, u8, usize};
+    }
+
+    #[cfg(feature = "quickcheck")]
+    pub use alloc::boxed::Box;
+
+    #[cfg(feature = "num")]
+    pub use num_traits;
+    #[cfg(feature = "quickcheck")]
+    pub use quickcheck;
+    #[cfg(feature = "rand010")]
+    pub use rand010;
+    #[cfg(feature = "rand08")]
+    pub use rand08;
+    #[cfg(feature = "rand09")]
+    pub use rand09;
+    #[cfg(feature = "serde")]
+    pub use serde_core;
+}
@@ -0,0 +1,34 @@
+//! Declaration of `Saturating`, which provides saturating arithmetic for ranged integers.
+
+use core::fmt;
+
+/// Intentionally-saturating arithmetic on a ranged integer.
+///
+/// Operators clamp the result to the bounds of the inner type, analogous to
+/// [`core::num::Saturating`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use deranged::{RangedU8, Saturating};
+/// let volume = Saturating(RangedU8::<0, 11>::new_static::<8>());
+/// assert_eq!((volume + 5).0.get(), 11);
+/// assert_eq!((volume - 10).0.get(), 0);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Saturating<T>(pub T);
+
+impl<T: fmt::Debug> fmt::Debug for Saturating<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Saturating<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
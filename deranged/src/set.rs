@@ -0,0 +1,431 @@
+//! A set of ranged integers, stored as a bit array.
+//!
+//! See [`RangedSet`] for details.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign,
+};
+
+use crate::traits::Dense;
+use crate::Ranged;
+
+/// The number of bits in each word of storage.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Asserts at compile time that `WORDS` is the number of words needed to store one bit for each
+/// value of the ranged integer type `K`.
+macro_rules! assert_words {
+    ($key:ident, $words:ident) => {
+        static_assert!(
+            has_words::<$key>($words),
+            "the number of words must be the number of values in the range of the key divided by \
+             64, rounded up",
+        );
+    };
+}
+
+/// Returns `true` if `words` is the number of words needed to store one bit for each value of the
+/// ranged integer type `K`.
+const fn has_words<K: Dense>(words: usize) -> bool {
+    matches!(K::LEN, Some(len) if len.div_ceil(WORD_BITS) == words)
+}
+
+/// A set of values of a ranged integer type.
+///
+/// Membership is stored as one bit for each value in the range of the key, so the set occupies
+/// `WORDS` 64-bit words regardless of how many values it contains. `WORDS` must be the number of
+/// values in the range of the key divided by 64, rounded up, which is checked at compile time.
+/// For a key of `RangedU8<0, 59>`, `WORDS` is 1.
+///
+/// The complement of a set contains every value in the range of the key that is not in the set.
+///
+/// # Example
+///
+/// ```rust
+/// # use deranged::{RangedSet, RangedU8};
+/// type Minute = RangedU8<0, 59>;
+///
+/// let mut schedule = RangedSet::<Minute, 1>::new();
+/// schedule.insert(Minute::new_static::<0>());
+/// schedule.insert(Minute::new_static::<15>());
+/// schedule.insert(Minute::new_static::<30>());
+/// schedule.insert(Minute::new_static::<45>());
+///
+/// assert!(schedule.contains(Minute::new_static::<15>()));
+/// assert_eq!(schedule.len(), 4);
+/// assert_eq!(schedule.complement().len(), 56);
+/// assert_eq!(
+///     schedule.iter().map(Minute::get).collect::<Vec<_>>(),
+///     [0, 15, 30, 45]
+/// );
+/// ```
+///
+/// Using a number of words that does not match the range of the key fails to compile.
+///
+/// ```rust,compile_fail
+/// # use deranged::{RangedSet, RangedU8};
+/// let set = RangedSet::<RangedU8<0, 64>, 1>::new();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangedSet<K, const WORDS: usize> {
+    /// One bit for each value, indexed by the distance of the value from its minimum. Bits past
+    /// the end of the range are always zero.
+    words: [u64; WORDS],
+    /// The type of the key.
+    key: PhantomData<K>,
+}
+
+impl<K: Ranged, const WORDS: usize> RangedSet<K, WORDS> {
+    /// Creates an empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        assert_words!(K, WORDS);
+        Self {
+            words: [0; WORDS],
+            key: PhantomData,
+        }
+    }
+
+    /// Creates a set containing every value in the range of the key.
+    #[inline]
+    pub const fn full() -> Self {
+        Self::new().complement()
+    }
+
+    /// Adds a value to the set, returning `true` if it was not already present.
+    #[inline]
+    pub fn insert(&mut self, value: K) -> bool {
+        let (word, mask) = self.bit_mut(value);
+        let inserted = *word & mask == 0;
+        *word |= mask;
+        inserted
+    }
+
+    /// Removes a value from the set, returning `true` if it was present.
+    #[inline]
+    pub fn remove(&mut self, value: K) -> bool {
+        let (word, mask) = self.bit_mut(value);
+        let removed = *word & mask != 0;
+        *word &= !mask;
+        removed
+    }
+
+    /// Returns `true` if the set contains the value.
+    #[inline]
+    pub fn contains(&self, value: K) -> bool {
+        assert_words!(K, WORDS);
+        let index = value.to_index();
+        // Safety: The index of the value is less than the number of values of `K`, so the word
+        // containing it is less than `WORDS`.
+        let word = unsafe { self.words.get_unchecked(index / WORD_BITS) };
+        word & (1 << (index % WORD_BITS)) != 0
+    }
+
+    /// Returns the number of values in the set.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < WORDS {
+            len += self.words[i].count_ones() as usize;
+            i += 1;
+        }
+        len
+    }
+
+    /// Returns `true` if the set contains no values.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        let mut i = 0;
+        while i < WORDS {
+            if self.words[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Removes every value from the set.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.words = [0; WORDS];
+    }
+
+    /// Returns the set of values in `self`, `other`, or both.
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        let mut words = self.words;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] |= other.words[i];
+            i += 1;
+        }
+        Self::from_words(words)
+    }
+
+    /// Returns the set of values in both `self` and `other`.
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        let mut words = self.words;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] &= other.words[i];
+            i += 1;
+        }
+        Self::from_words(words)
+    }
+
+    /// Returns the set of values in `self` but not in `other`.
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        let mut words = self.words;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] &= !other.words[i];
+            i += 1;
+        }
+        Self::from_words(words)
+    }
+
+    /// Returns the set of values in exactly one of `self` and `other`.
+    #[inline]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        let mut words = self.words;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] ^= other.words[i];
+            i += 1;
+        }
+        Self::from_words(words)
+    }
+
+    /// Returns the set of values in the range of the key that are not in `self`.
+    #[inline]
+    pub const fn complement(self) -> Self {
+        let mut words = self.words;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] = !words[i];
+            i += 1;
+        }
+        Self::from_words(words)
+    }
+
+    /// Returns `true` if every value in `self` is also in `other`.
+    #[inline]
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        self.difference(*other).is_empty()
+    }
+
+    /// Returns `true` if every value in `other` is also in `self`.
+    #[inline]
+    pub const fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if `self` and `other` have no values in common.
+    #[inline]
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(*other).is_empty()
+    }
+
+    /// Returns an iterator over the values in the set, in ascending order.
+    #[inline]
+    pub const fn iter(&self) -> Iter<K, WORDS> {
+        Iter {
+            words: self.words,
+            front: 0,
+            back: WORDS,
+            key: PhantomData,
+        }
+    }
+
+    /// Creates a set from its words, clearing any bits past the end of the range.
+    #[inline]
+    const fn from_words(mut words: [u64; WORDS]) -> Self {
+        assert_words!(K, WORDS);
+        let len = match K::LEN {
+            Some(len) => len,
+            None => 0,
+        };
+        if len % WORD_BITS != 0 {
+            words[WORDS - 1] &= (1 << (len % WORD_BITS)) - 1;
+        }
+        Self {
+            words,
+            key: PhantomData,
+        }
+    }
+
+    /// Returns the word containing the bit for the value and a mask selecting that bit.
+    #[inline]
+    fn bit_mut(&mut self, value: K) -> (&mut u64, u64) {
+        assert_words!(K, WORDS);
+        let index = value.to_index();
+        // Safety: The index of the value is less than the number of values of `K`, so the word
+        // containing it is less than `WORDS`.
+        let word = unsafe { self.words.get_unchecked_mut(index / WORD_BITS) };
+        (word, 1 << (index % WORD_BITS))
+    }
+}
+
+impl<K: Ranged, const WORDS: usize> Default for RangedSet<K, WORDS> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ranged, const WORDS: usize> fmt::Debug for RangedSet<K, WORDS> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ranged, const WORDS: usize> FromIterator<K> for RangedSet<K, WORDS> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K: Ranged, const WORDS: usize> Extend<K> for RangedSet<K, WORDS> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<K: Ranged, const WORDS: usize> IntoIterator for RangedSet<K, WORDS> {
+    type Item = K;
+    type IntoIter = Iter<K, WORDS>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Ranged, const WORDS: usize> IntoIterator for &RangedSet<K, WORDS> {
+    type Item = K;
+    type IntoIter = Iter<K, WORDS>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Implements a set operator and its assigning counterpart using the given method.
+macro_rules! impl_set_op {
+    ($trait:ident $op:ident $assign_trait:ident $assign_op:ident => $method:ident) => {
+        impl<K: Ranged, const WORDS: usize> $trait for RangedSet<K, WORDS> {
+            type Output = Self;
+
+            #[inline]
+            fn $op(self, rhs: Self) -> Self::Output {
+                self.$method(rhs)
+            }
+        }
+
+        impl<K: Ranged, const WORDS: usize> $assign_trait for RangedSet<K, WORDS> {
+            #[inline]
+            fn $assign_op(&mut self, rhs: Self) {
+                *self = self.$method(rhs);
+            }
+        }
+    };
+}
+
+impl_set_op!(BitOr bitor BitOrAssign bitor_assign => union);
+impl_set_op!(BitAnd bitand BitAndAssign bitand_assign => intersection);
+impl_set_op!(BitXor bitxor BitXorAssign bitxor_assign => symmetric_difference);
+impl_set_op!(Sub sub SubAssign sub_assign => difference);
+
+impl<K: Ranged, const WORDS: usize> Not for RangedSet<K, WORDS> {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+/// An iterator over the values in a [`RangedSet`], in ascending order.
+///
+/// This struct is created by [`RangedSet::iter`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct Iter<K, const WORDS: usize> {
+    /// The bits of the values that have not been yielded.
+    words: [u64; WORDS],
+    /// The index of the lowest word that may have bits set.
+    front: usize,
+    /// One past the index of the highest word that may have bits set.
+    back: usize,
+    /// The type of the key.
+    key: PhantomData<K>,
+}
+
+impl<K: Ranged, const WORDS: usize> Iterator for Iter<K, WORDS> {
+    type Item = K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            let word = &mut self.words[self.front];
+            if *word != 0 {
+                let bit = word.trailing_zeros() as usize;
+                *word &= *word - 1;
+                // Safety: Only bits for values in the range of the key are ever set.
+                return Some(unsafe { K::from_index_unchecked(self.front * WORD_BITS + bit) });
+            }
+            self.front += 1;
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<K: Ranged, const WORDS: usize> DoubleEndedIterator for Iter<K, WORDS> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            let word = &mut self.words[self.back - 1];
+            if *word != 0 {
+                let bit = (u64::BITS - 1 - word.leading_zeros()) as usize;
+                *word &= !(1 << bit);
+                // Safety: Only bits for values in the range of the key are ever set.
+                return Some(unsafe { K::from_index_unchecked((self.back - 1) * WORD_BITS + bit) });
+            }
+            self.back -= 1;
+        }
+        None
+    }
+}
+
+impl<K: Ranged, const WORDS: usize> ExactSizeIterator for Iter<K, WORDS> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.words[self.front..self.back]
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+}
+
+impl<K: Ranged, const WORDS: usize> FusedIterator for Iter<K, WORDS> {}
@@ -1,15 +1,16 @@
 extern crate std;
 
 use core::hash::Hash;
-use std::format;
 use std::prelude::rust_2021::*;
+use std::{format, panic};
 
 use crate::{
-    IntErrorKind, OptionRangedI128, OptionRangedI16, OptionRangedI32, OptionRangedI64,
-    OptionRangedI8, OptionRangedIsize, OptionRangedU128, OptionRangedU16, OptionRangedU32,
-    OptionRangedU64, OptionRangedU8, OptionRangedUsize, ParseIntError, RangedI128, RangedI16,
-    RangedI32, RangedI64, RangedI8, RangedIsize, RangedU128, RangedU16, RangedU32, RangedU64,
-    RangedU8, RangedUsize, TryFromIntError,
+    AllValues, ErrorInt, IntErrorKind, Modular, OptionRangedI128, OptionRangedI16, OptionRangedI32,
+    OptionRangedI64, OptionRangedI8, OptionRangedIsize, OptionRangedU128, OptionRangedU16,
+    OptionRangedU32, OptionRangedU64, OptionRangedU8, OptionRangedUsize, OptionalRanged,
+    ParseIntError, RangeErrorKind, Ranged, RangedChar, RangedF32, RangedF64, RangedI128, RangedI16,
+    RangedI32, RangedI64, RangedI8, RangedIsize, RangedMap, RangedSet, RangedSliceExt, RangedU128,
+    RangedU16, RangedU32, RangedU64, RangedU8, RangedUsize, Saturating,
 };
 
 #[test]
@@ -24,6 +25,633 @@ fn test_ranged_conversion() {
     // let _ : RangedI8<-4, 4> = RangedI16::<-5, 5>::new_static::<3>().into();
 }
 
+#[test]
+fn widening_mul_ranged() {
+    let product: RangedU16<0, 65_025> =
+        RangedU8::<0, 255>::MAX.widening_mul_ranged(RangedU8::<0, 255>::MAX);
+    assert_eq!(product.get(), 65_025);
+    let product: RangedI16<-10_000, 10_000> =
+        RangedI8::<-100, 100>::MIN.widening_mul_ranged(RangedI8::<-100, 100>::MAX);
+    assert_eq!(product.get(), -10_000);
+    let product: RangedU128<0, { u64::MAX as u128 * u64::MAX as u128 }> =
+        RangedU64::<0, { u64::MAX }>::MAX.widening_mul_ranged(RangedU64::<0, { u64::MAX }>::MAX);
+    assert_eq!(product.get(), u64::MAX as u128 * u64::MAX as u128);
+    let product: RangedI128<-50, 50> =
+        RangedIsize::<-5, 5>::MIN.widening_mul_ranged(RangedIsize::<-10, 10>::MAX);
+    assert_eq!(product.get(), -50);
+}
+
+#[test]
+fn array_index() {
+    let mut table = [2, 3, 5, 7, 11];
+    for index in RangedUsize::<0, 4>::all() {
+        assert_eq!(table[index], table[index.get()]);
+    }
+    assert_eq!(table[RangedUsize::<1, 3>::MAX], 7);
+
+    table[RangedUsize::<0, 4>::MIN] = 1;
+    table[RangedUsize::<2, 2>::MIN] += 1;
+    assert_eq!(table, [1, 3, 6, 7, 11]);
+
+    // Indices that are not ranged still infer as `usize`.
+    let index = 3;
+    assert_eq!(table[index], 7);
+    assert_eq!(table[0], 1);
+    assert_eq!(table[1..3], [3, 6]);
+    table[4] = 13;
+    assert_eq!(table[4], 13);
+}
+
+#[test]
+fn slice_get_ranged() {
+    let table: &mut [i32] = &mut [2, 3, 5];
+    assert_eq!(
+        table.get_ranged(RangedUsize::<0, 4>::new_static::<2>()),
+        Some(&5)
+    );
+    assert_eq!(table.get_ranged(RangedUsize::<0, 4>::MAX), None);
+
+    if let Some(value) = table.get_ranged_mut(RangedUsize::<0, 4>::MIN) {
+        *value = 1;
+    }
+    assert!(table.get_ranged_mut(RangedUsize::<3, 4>::MIN).is_none());
+    assert_eq!(table, [1, 3, 5]);
+}
+
+#[test]
+fn ranged_map() {
+    type Key = RangedI8<-3, 3>;
+    let key = |value| Key::new(value).expect("key is in range");
+
+    let mut map = RangedMap::<Key, &str, 7>::new();
+    assert!(map.is_empty());
+    assert_eq!(map.insert(key(-3), "a"), None);
+    assert_eq!(map.insert(key(3), "b"), None);
+    assert_eq!(map.insert(key(3), "c"), Some("b"));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(key(-3)), Some(&"a"));
+    assert_eq!(map.get(key(0)), None);
+    assert!(map.contains_key(key(3)));
+    assert_eq!(format!("{map:?}"), r#"{-3: "a", 3: "c"}"#);
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        [(key(-3), &"a"), (key(3), &"c")]
+    );
+    assert_eq!(
+        map.iter().rev().collect::<Vec<_>>(),
+        [(key(3), &"c"), (key(-3), &"a")]
+    );
+    assert_eq!(map.remove(key(-3)), Some("a"));
+    assert_eq!(map.remove(key(-3)), None);
+    map.clear();
+    assert_eq!(map, RangedMap::default());
+
+    let mut squares = RangedMap::<Key, i32, 7>::from_fn(|key| i32::from(key.get()).pow(2));
+    assert_eq!(squares.len(), 7);
+    assert_eq!(squares.get(key(-2)), Some(&4));
+    *squares.get_mut(key(1)).expect("value is present") = 0;
+    for (_, value) in &mut squares {
+        *value += 1;
+    }
+    let doubled = squares.map(|key, value| (key.get(), value * 2));
+    assert_eq!(doubled.get(key(-3)), Some(&(-3, 20)));
+    assert_eq!(doubled.get(key(1)), Some(&(1, 2)));
+    assert_eq!(
+        doubled
+            .into_iter()
+            .map(|(_, (_, value))| value)
+            .collect::<Vec<_>>(),
+        [20, 10, 4, 2, 2, 10, 20]
+    );
+
+    let mut counts = [(key(1), 1), (key(2), 2)]
+        .into_iter()
+        .collect::<RangedMap<Key, u32, 7>>();
+    *counts.entry(key(1)).or_insert(0) += 10;
+    *counts.entry(key(0)).or_default() += 5;
+    counts.entry(key(2)).and_modify(|value| *value = 0);
+    counts.entry(key(-1)).and_modify(|value| *value = 0);
+    assert_eq!(
+        counts
+            .entry(key(-2))
+            .or_insert_with_key(|key| key.get().unsigned_abs().into()),
+        &2
+    );
+    assert_eq!(counts.entry(key(-2)).key(), key(-2));
+    assert_eq!(counts.entry(key(-2)).remove(), Some(2));
+    assert_eq!(
+        counts.iter().collect::<Vec<_>>(),
+        [(key(0), &5), (key(1), &11), (key(2), &0)]
+    );
+
+    let full = RangedMap::<RangedU8<0, 255>, u8, 256>::from_fn(RangedU8::get);
+    assert_eq!(full.get(RangedU8::MAX), Some(&255));
+    assert_eq!(full.iter().next_back(), Some((RangedU8::MAX, &255)));
+}
+
+#[test]
+fn ranged_set() {
+    type Minute = RangedU8<0, 59>;
+    let minute = |value| Minute::new(value).expect("minute is in range");
+    let set = |values: &[u8]| {
+        values
+            .iter()
+            .copied()
+            .map(minute)
+            .collect::<RangedSet<Minute, 1>>()
+    };
+
+    let mut quarters = RangedSet::<Minute, 1>::new();
+    assert!(quarters.is_empty());
+    assert!(quarters.insert(minute(0)));
+    assert!(quarters.insert(minute(15)));
+    assert!(!quarters.insert(minute(15)));
+    quarters.extend([minute(30), minute(45)]);
+    assert_eq!(quarters.len(), 4);
+    assert!(quarters.contains(minute(30)));
+    assert!(!quarters.contains(minute(31)));
+    assert_eq!(format!("{quarters:?}"), "{0, 15, 30, 45}");
+    assert_eq!(
+        quarters.iter().rev().map(Minute::get).collect::<Vec<_>>(),
+        [45, 30, 15, 0]
+    );
+    assert_eq!(quarters.iter().len(), 4);
+
+    let halves = set(&[0, 30]);
+    let odd = set(&[15, 59]);
+    assert_eq!(halves | odd, set(&[0, 15, 30, 59]));
+    assert_eq!(quarters & odd, set(&[15]));
+    assert_eq!(quarters - halves, set(&[15, 45]));
+    assert_eq!(quarters ^ odd, set(&[0, 30, 45, 59]));
+    assert_eq!((!quarters).len(), 56);
+    assert_eq!(!!quarters, quarters);
+    assert_eq!(RangedSet::<Minute, 1>::full().len(), 60);
+    assert_eq!(
+        RangedSet::<Minute, 1>::full().iter().nth(59),
+        Some(minute(59))
+    );
+    assert!(halves.is_subset(&quarters));
+    assert!(quarters.is_superset(&halves));
+    assert!(halves.is_disjoint(&odd));
+    assert!(!quarters.is_disjoint(&odd));
+
+    let mut assigned = quarters;
+    assigned -= halves;
+    assigned |= odd;
+    assigned &= !set(&[45]);
+    assigned ^= set(&[0]);
+    assert_eq!(assigned, set(&[0, 15, 59]));
+    assert!(assigned.remove(minute(0)));
+    assert!(!assigned.remove(minute(0)));
+    assigned.clear();
+    assert_eq!(assigned, RangedSet::default());
+
+    const FULL: RangedSet<RangedI16<-100, 100>, 4> = RangedSet::full();
+    assert_eq!(FULL.len(), 201);
+    assert_eq!(FULL.iter().next(), Some(RangedI16::MIN));
+    assert_eq!(FULL.iter().next_back(), Some(RangedI16::MAX));
+    assert_eq!(FULL.iter().nth(200), Some(RangedI16::MAX));
+    assert!(FULL.complement().is_empty());
+}
+
+crate::ranged_newtype! {
+    /// A percentage, used to test `ranged_newtype!`.
+    struct Percent(u8 in 0..=100) {
+        error: "a percentage must be between 0 and 100",
+        suffix: "%",
+    }
+}
+
+crate::ranged_newtype! {
+    /// A UTC offset in minutes, used to test `ranged_newtype!`.
+    pub(crate) struct Offset(i16 in -720..=840);
+}
+
+crate::ranged_newtype! {
+    /// A day of the week, used to test that `ranged_newtype!` applies attributes to the newtype.
+    /// The name would be linted if the attribute were not applied.
+    #[allow(non_camel_case_types)]
+    #[must_use]
+    struct weekday(u8 in 1..=7);
+}
+
+#[test]
+fn ranged_newtype() {
+    let percent = Percent::new_static::<42>();
+    assert_eq!(percent.get(), 42);
+    assert_eq!(Percent::new(42), Some(percent));
+    assert_eq!(Percent::new(101), None);
+    assert_eq!(Percent::MIN.get(), 0);
+    assert_eq!(Percent::MAX.get(), 100);
+    assert!(Percent::MIN < percent);
+    assert_eq!(percent.to_ranged(), RangedU8::<0, 100>::new_static::<42>());
+    assert_eq!(Percent::from_ranged(percent.to_ranged()), percent);
+    assert_eq!(Percent::from(RangedU8::<0, 100>::MAX), Percent::MAX);
+    assert_eq!(RangedU8::<0, 100>::from(percent).get(), 42);
+    assert_eq!(u8::from(percent), 42);
+    assert_eq!(weekday::new_static::<3>().get(), 3);
+
+    assert_eq!(format!("{percent}"), "42%");
+    assert_eq!(format!("{percent:?}"), "Percent(42)");
+    assert_eq!("42%".parse(), Ok(percent));
+    assert_eq!("42".parse(), Ok(percent));
+    let err = "101%"
+        .parse::<Percent>()
+        .expect_err("value is out of range");
+    assert_eq!(err.to_string(), "a percentage must be between 0 and 100");
+    assert_eq!(
+        err.message(),
+        Some("a percentage must be between 0 and 100")
+    );
+    assert_eq!(err.inner().kind(), &IntErrorKind::PosOverflow);
+    assert!(core::error::Error::source(&err).is_some());
+    let err = Percent::try_from(101).expect_err("value is out of range");
+    assert_eq!(err.to_string(), "a percentage must be between 0 and 100");
+    assert_eq!(err.into_inner().value::<u8>(), Some(101));
+    assert_eq!(Percent::try_from(100), Ok(Percent::MAX));
+
+    let offset = Offset::new(-300).expect("value is in range");
+    assert_eq!(format!("{offset}"), "-300");
+    assert_eq!(format!("{offset:?}"), "Offset(-300)");
+    assert_eq!("-300".parse(), Ok(offset));
+    let err = Offset::try_from(900).expect_err("value is out of range");
+    assert_eq!(err.to_string(), "900 is not in the range -720..=840");
+    assert_eq!(err.message(), None);
+    assert!(core::error::Error::source(&err).is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ranged_newtype_serde() -> serde_json::Result<()> {
+    assert_eq!(serde_json::to_string(&Percent::new_static::<42>())?, "42");
+    assert_eq!(
+        serde_json::from_str::<Percent>("42")?,
+        Percent::new_static::<42>()
+    );
+    let err = serde_json::from_str::<Percent>("101").expect_err("value is out of range");
+    assert!(err
+        .to_string()
+        .starts_with("a percentage must be between 0 and 100"));
+
+    assert_eq!(serde_json::from_str::<Offset>("-720")?, Offset::MIN);
+    assert!(serde_json::from_str::<Offset>("900").is_err());
+    Ok(())
+}
+
+#[cfg(feature = "rand09")]
+#[test]
+fn ranged_newtype_rand() {
+    let percent: Percent = rand09::random();
+    assert!(percent <= Percent::MAX);
+}
+
+/// Asserts that two types are the same.
+#[cfg(feature = "macros")]
+fn assert_same_type<T>(_: core::marker::PhantomData<T>, _: core::marker::PhantomData<T>) {}
+
+#[cfg(feature = "macros")]
+#[test]
+fn int_macro() {
+    use core::marker::PhantomData as P;
+
+    assert_same_type(P::<crate::int!(0, 100)>, P::<RangedU8<0, 100>>);
+    assert_same_type(P::<crate::int!(0i, 100)>, P::<RangedI8<0, 100>>);
+    assert_same_type(P::<crate::int!(-5, 5)>, P::<RangedI8<-5, 5>>);
+    assert_same_type(P::<crate::int!(0..=100)>, P::<RangedU8<0, 100>>);
+    assert_same_type(P::<crate::int!(0..256)>, P::<RangedU8<0, 255>>);
+    assert_same_type(P::<crate::int!(-5..5)>, P::<RangedI8<-5, 4>>);
+    assert_same_type(P::<crate::int!(..=1000)>, P::<RangedU16<0, 1000>>);
+    assert_same_type(P::<crate::int!(..1)>, P::<RangedU8<0, 0>>);
+    assert_same_type(P::<crate::int!(5, 5)>, P::<RangedU8<5, 5>>);
+    assert_same_type(P::<crate::opt_int!(0..=100)>, P::<OptionRangedU8<0, 100>>);
+    assert_same_type(P::<crate::opt_int!(-10..10)>, P::<OptionRangedI8<-10, 9>>);
+    assert_same_type(P::<crate::int!(-10..-5)>, P::<RangedI8<-10, -6>>);
+    assert_same_type(P::<crate::int!(-10..=-5)>, P::<RangedI8<-10, -5>>);
+    assert_same_type(P::<crate::opt_int!(-10..-5)>, P::<OptionRangedI8<-10, -6>>);
+
+    const BUF_LEN: u16 = 64;
+    const OFFSET: i8 = 5;
+    assert_same_type(P::<crate::int!(u16; 0, BUF_LEN - 1)>, P::<RangedU16<0, 63>>);
+    assert_same_type(P::<crate::int!(u16; 0..BUF_LEN)>, P::<RangedU16<0, 63>>);
+    assert_same_type(P::<crate::int!(u16; ..=BUF_LEN)>, P::<RangedU16<0, 64>>);
+    assert_same_type(P::<crate::int!(u16; 1, 2)>, P::<RangedU16<1, 2>>);
+    assert_same_type(P::<crate::int!(i8; -OFFSET, OFFSET)>, P::<RangedI8<-5, 5>>);
+    assert_same_type(
+        P::<crate::int!(usize; 0, { BUF_LEN as usize })>,
+        P::<RangedUsize<0, 64>>,
+    );
+    assert_same_type(
+        P::<crate::opt_int!(i8; -OFFSET..=OFFSET)>,
+        P::<OptionRangedI8<-5, 5>>,
+    );
+
+    assert_same_type(P::<crate::int!(0, 100u32)>, P::<RangedU32<0, 100>>);
+    assert_same_type(P::<crate::int!(0usize, 9)>, P::<RangedUsize<0, 9>>);
+    assert_same_type(P::<crate::int!(-1isize, 1isize)>, P::<RangedIsize<-1, 1>>);
+    assert_same_type(P::<crate::int!(0i64..10)>, P::<RangedI64<0, 9>>);
+    assert_same_type(P::<crate::int!(..=10u16)>, P::<RangedU16<0, 10>>);
+    assert_same_type(P::<crate::int!(..10i16)>, P::<RangedI16<0, 9>>);
+    assert_same_type(P::<crate::int!(u32; 0, 100)>, P::<RangedU32<0, 100>>);
+    assert_same_type(P::<crate::int!(isize; -5..5)>, P::<RangedIsize<-5, 4>>);
+    assert_same_type(P::<crate::int!(i16; ..=5)>, P::<RangedI16<0, 5>>);
+    assert_same_type(P::<crate::int!(u32; 0u, 5u)>, P::<RangedU32<0, 5>>);
+    assert_same_type(P::<crate::int!(i16; -5i, 5)>, P::<RangedI16<-5, 5>>);
+    assert_same_type(P::<crate::int!(u64; 0u64, 5)>, P::<RangedU64<0, 5>>);
+    assert_same_type(P::<crate::int!(isize; ..5isize)>, P::<RangedIsize<0, 4>>);
+    assert_same_type(
+        P::<crate::opt_int!(u32; 0, 5u32)>,
+        P::<OptionRangedU32<0, 5>>,
+    );
+    assert_same_type(P::<crate::int!(..=5i)>, P::<RangedI8<0, 5>>);
+    assert_same_type(P::<crate::int!(..5i64)>, P::<RangedI64<0, 4>>);
+    assert_same_type(
+        P::<crate::int!(0, 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF)>,
+        P::<RangedU128<0, { u128::MAX }>>,
+    );
+    assert_same_type(
+        P::<crate::int!(0, 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_u128)>,
+        P::<RangedU128<0, { u128::MAX }>>,
+    );
+    assert_same_type(
+        P::<crate::opt_int!(0, 5usize)>,
+        P::<OptionRangedUsize<0, 5>>,
+    );
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn val_macro() {
+    const TABLE: [RangedU8<0, 100>; 2] = [crate::val!(0 in 0..=100), crate::val!(42 in 0..=100)];
+
+    assert_eq!(TABLE[1], RangedU8::<0, 100>::new_static::<42>());
+    assert_eq!(
+        crate::val!(-3 in -5..5),
+        RangedI8::<-5, 4>::new_static::<-3>()
+    );
+    assert_eq!(crate::val!(-128 in -128, 127), RangedI8::<-128, 127>::MIN);
+    assert_eq!(
+        crate::val!(7 in 0, 10usize),
+        RangedUsize::<0, 10>::new_static::<7>()
+    );
+    assert_eq!(crate::val!(1000 in ..=1000).get(), 1000_u16);
+    assert_eq!(
+        crate::val!(-7 in -10..-5),
+        RangedI8::<-10, -6>::new_static::<-7>()
+    );
+
+    const BUF_LEN: u16 = 64;
+    assert_eq!(
+        crate::val!(7 in u32; 0, 10),
+        RangedU32::<0, 10>::new_static::<7>()
+    );
+    assert_eq!(crate::val!(63 in u16; 0..BUF_LEN), RangedU16::<0, 63>::MAX);
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn ranged_repr_derive() {
+    use crate::RangedRepr;
+
+    // Implicit discriminants start at zero and are contiguous.
+    #[derive(Debug, Clone, Copy, PartialEq, RangedRepr)]
+    enum Implicit {
+        A,
+        B,
+        C,
+    }
+
+    // Implicit discriminants continue from the previous explicit one.
+    #[derive(Debug, Clone, Copy, PartialEq, RangedRepr)]
+    enum Mixed {
+        A = -2,
+        B,
+        C = 5,
+        D,
+    }
+
+    // The inner type is given by `#[repr(..)]`, even though a smaller one would suffice.
+    #[derive(Debug, Clone, Copy, PartialEq, RangedRepr)]
+    #[repr(u32)]
+    enum Repr {
+        A = 1,
+        B,
+    }
+
+    // Without `#[repr(..)]`, the smallest inner type that fits every discriminant is chosen.
+    #[derive(Debug, Clone, Copy, PartialEq, RangedRepr)]
+    enum Wide {
+        A = 0,
+        B = 256,
+    }
+
+    // The annotated types only compile if the derive chose them.
+    let implicit: RangedU8<0, 2> = Implicit::C.into();
+    assert_eq!(implicit.get(), 2);
+    let mixed: RangedI8<-2, 6> = Mixed::B.into();
+    assert_eq!(mixed.get(), -1);
+    let repr: RangedU32<1, 2> = Repr::B.into();
+    assert_eq!(repr.get(), 2);
+    let wide: RangedU16<0, 256> = Wide::B.into();
+    assert_eq!(wide.get(), 256);
+
+    // Contiguous discriminants convert back with `From`.
+    for (value, variant) in [(0, Implicit::A), (1, Implicit::B), (2, Implicit::C)] {
+        assert_eq!(
+            Implicit::from(RangedU8::<0, 2>::new(value).expect("value is in range")),
+            variant
+        );
+    }
+    assert_eq!(Repr::from(RangedU32::<1, 2>::MIN), Repr::A);
+    assert_eq!(Repr::from(RangedU32::<1, 2>::MAX), Repr::B);
+
+    // Non-contiguous discriminants convert back with `TryFrom`, returning the input on failure.
+    for (value, variant) in [(-2, Mixed::A), (-1, Mixed::B), (5, Mixed::C), (6, Mixed::D)] {
+        assert_eq!(
+            Mixed::try_from(RangedI8::<-2, 6>::new(value).expect("value is in range")),
+            Ok(variant)
+        );
+    }
+    for value in 0..=4 {
+        let value = RangedI8::<-2, 6>::new(value).expect("value is in range");
+        assert_eq!(Mixed::try_from(value), Err(value));
+    }
+    assert_eq!(Wide::try_from(RangedU16::<0, 256>::MAX), Ok(Wide::B));
+    let value = RangedU16::<0, 256>::new_static::<1>();
+    assert_eq!(Wide::try_from(value), Err(value));
+}
+
+#[test]
+fn ranged_float() {
+    type Unit = RangedF32<{ (-1.0_f32).to_bits() }, { 1.0_f32.to_bits() }>;
+    type Wide = RangedF64<{ 0.0_f64.to_bits() }, { 1e300_f64.to_bits() }>;
+
+    assert_eq!(Unit::MIN.get(), -1.0);
+    assert_eq!(Unit::MAX.get(), 1.0);
+    assert_eq!(Unit::new(0.5).map(Unit::get), Some(0.5));
+    assert!(Unit::new(1.5).is_none());
+    assert!(Unit::new(f32::NAN).is_none());
+    assert!(Unit::new(f32::INFINITY).is_none());
+    assert_eq!(Unit::new_saturating(2.0), Unit::MAX);
+    assert_eq!(Unit::new_saturating(f32::NEG_INFINITY), Unit::MIN);
+    assert_eq!(Unit::new_saturating(f32::NAN), Unit::MIN);
+
+    let half = Unit::new(0.5).expect("value is in range");
+    assert_eq!(half.checked_add(0.25).map(Unit::get), Some(0.75));
+    assert!(half.checked_add(1.0).is_none());
+    assert_eq!(half.checked_sub(1.0).map(Unit::get), Some(-0.5));
+    assert!(half.checked_mul(f32::NAN).is_none());
+    assert!(half.checked_div(0.0).is_none());
+    assert_eq!(half.checked_div(-2.0).map(Unit::get), Some(-0.25));
+    assert_eq!(half.saturating_add(1.0), Unit::MAX);
+    assert_eq!(half.saturating_sub(2.0), Unit::MIN);
+    assert_eq!(half.saturating_mul(4.0), Unit::MAX);
+    assert_eq!(half.saturating_div(f32::NAN), Unit::MIN);
+
+    let negative_zero = Unit::new(-0.0).expect("value is in range");
+    let zero = Unit::new(0.0).expect("value is in range");
+    assert_eq!(negative_zero, zero);
+    assert_eq!(negative_zero.get().to_bits(), 0.0_f32.to_bits());
+    let hash = |value: &Unit| {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        core::hash::Hasher::finish(&hasher)
+    };
+    assert_eq!(hash(&negative_zero), hash(&zero));
+
+    assert!(Unit::MIN < zero);
+    assert_eq!(zero.cmp(&half), core::cmp::Ordering::Less);
+    assert_eq!(format!("{half} {half:?}"), "0.5 0.5");
+    assert_eq!(f32::from(half), 0.5);
+    assert_eq!(Wide::MAX.get(), 1e300);
+    assert!(Wide::new(-1.0).is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ranged_float_serde() -> serde_json::Result<()> {
+    type Unit = RangedF64<{ 0.0_f64.to_bits() }, { 1.0_f64.to_bits() }>;
+
+    let half = Unit::new(0.5).expect("value is in range");
+    assert_eq!(serde_json::to_string(&half)?, "0.5");
+    assert_eq!(serde_json::from_str::<Unit>("0.5")?, half);
+    let err = serde_json::from_str::<Unit>("1.5").expect_err("value is out of range");
+    // The range is only included in the message when it can be formatted.
+    #[cfg(feature = "alloc")]
+    assert!(err.to_string().contains("a number in the range 0.0..=1.0"));
+    #[cfg(not(feature = "alloc"))]
+    assert!(err.to_string().contains("a number in the valid range"));
+    Ok(())
+}
+
+#[cfg(all(feature = "rand08", feature = "rand09", feature = "rand010"))]
+#[test]
+fn ranged_float_rand() {
+    type Unit = RangedF32<{ (-1.0_f32).to_bits() }, { 1.0_f32.to_bits() }>;
+    type Full = RangedF64<{ (-f64::MAX).to_bits() }, { f64::MAX.to_bits() }>;
+    type Point = RangedF64<{ 1.5_f64.to_bits() }, { 1.5_f64.to_bits() }>;
+
+    for _ in 0..100 {
+        let value: Unit = rand09::random();
+        assert!((-1.0..=1.0).contains(&value.get()));
+
+        // The width of the range overflows to infinity.
+        let value: Full = rand08::random();
+        assert!(value.get().is_finite());
+        let value: Full = rand09::random();
+        assert!(value.get().is_finite());
+        let value: Full = rand010::random();
+        assert!(value.get().is_finite());
+
+        let value: Point = rand09::random();
+        assert_eq!(value.get(), 1.5);
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+#[test]
+fn ranged_float_quickcheck() {
+    type Unit = RangedF32<{ (-1.0_f32).to_bits() }, { 1.0_f32.to_bits() }>;
+    type Full = RangedF64<{ (-f64::MAX).to_bits() }, { f64::MAX.to_bits() }>;
+
+    #[allow(trivial_casts)]
+    quickcheck::quickcheck((|val: Unit| (-1.0..=1.0).contains(&val.get())) as fn(Unit) -> bool);
+    #[allow(trivial_casts)]
+    quickcheck::quickcheck((|val: Full| val.get().is_finite()) as fn(Full) -> bool);
+}
+
+#[test]
+fn ranged_char() {
+    type Hex = RangedChar<'0', 'f'>;
+    type Letter = RangedChar<'A', 'Z'>;
+
+    assert_eq!(Letter::MIN.get(), 'A');
+    assert_eq!(Letter::MAX.get(), 'Z');
+    assert_eq!(Letter::new('Q').map(Letter::get), Some('Q'));
+    assert!(Letter::new('a').is_none());
+    assert_eq!(Letter::new_static::<'C'>().get(), 'C');
+    assert_eq!(Letter::new_saturating('0'), Letter::MIN);
+    assert_eq!(Letter::new_saturating('z'), Letter::MAX);
+
+    assert_eq!(Hex::from_digit(11, 16).map(Hex::get), Some('b'));
+    assert!(Hex::from_digit(20, 36).is_none());
+    assert!(Hex::from_digit(16, 16).is_none());
+    assert_eq!(Hex::new('c').and_then(|digit| digit.to_digit(16)), Some(12));
+    assert_eq!(Hex::new('c').and_then(|digit| digit.to_digit(10)), None);
+
+    assert_eq!("K".parse::<Letter>(), Ok(Letter::new_static::<'K'>()));
+    let err = "k".parse::<Letter>().expect_err("value is out of range");
+    assert_eq!(err.value(), Some('k'));
+    assert_eq!((err.min(), err.max()), ('A', 'Z'));
+    assert_eq!(err.to_string(), "'k' is not in the range 'A'..='Z'");
+    let err = "KK".parse::<Letter>().expect_err("string is too long");
+    assert_eq!(err.value(), None);
+    assert_eq!(err.to_string(), "string must contain exactly one char");
+    assert!("".parse::<Letter>().is_err());
+
+    let letter = Letter::new_static::<'C'>();
+    assert_eq!(letter.to_ranged_u32::<0x41, 0x5A>().get(), 0x43);
+    assert_eq!(letter.to_ranged_u32::<0, { u32::MAX }>().get(), 0x43);
+    assert_eq!(char::from(letter), 'C');
+    assert_eq!(u32::from(letter), 0x43);
+    assert_eq!(format!("{letter} {letter:?}"), "C 'C'");
+    assert!(Letter::MIN < letter);
+
+    let letters = Letter::all().map(Letter::get).collect::<String>();
+    assert_eq!(letters, "ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+    assert_eq!(Letter::all().size_hint(), (26, Some(26)));
+    assert_eq!(Letter::all().next_back(), Some(Letter::MAX));
+    assert_eq!(Letter::all().rev().nth(1).map(Letter::get), Some('Y'));
+
+    type AroundSurrogates = RangedChar<'\u{D7FE}', '\u{E001}'>;
+    let values = AroundSurrogates::all().map(u32::from).collect::<Vec<_>>();
+    assert_eq!(values, [0xD7FE, 0xD7FF, 0xE000, 0xE001]);
+    assert_eq!(AroundSurrogates::all().size_hint(), (4, Some(4)));
+    let values = AroundSurrogates::all()
+        .rev()
+        .map(u32::from)
+        .collect::<Vec<_>>();
+    assert_eq!(values, [0xE001, 0xE000, 0xD7FF, 0xD7FE]);
+    assert_eq!(
+        RangedChar::<'\0', { char::MAX }>::all().size_hint(),
+        (0x10_F800, Some(0x10_F800))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ranged_char_serde() -> serde_json::Result<()> {
+    type Letter = RangedChar<'A', 'Z'>;
+
+    assert_eq!(serde_json::to_string(&Letter::MIN)?, r#""A""#);
+    assert_eq!(
+        serde_json::from_str::<Letter>(r#""Q""#)?,
+        Letter::new_static::<'Q'>()
+    );
+    assert!(serde_json::from_str::<Letter>(r#""q""#).is_err());
+    assert!(serde_json::from_str::<Letter>(r#""QQ""#).is_err());
+    Ok(())
+}
+
 macro_rules! if_signed {
     (signed $($x:tt)*) => { $($x)* };
     (unsigned $($x:tt)*) => {};
@@ -36,73 +664,122 @@ macro_rules! if_unsigned {
 
 #[test]
 fn errors() {
+    let err = RangedU8::<1, 12>::try_from(13).expect_err("value is out of range");
+    assert_eq!(err.to_string(), "13 is not in the range 1..=12");
+    assert_eq!(err.kind(), RangeErrorKind::AboveMax);
+    assert_eq!(err.value::<u8>(), Some(13));
+    assert_eq!(err.value::<i128>(), Some(13));
+    assert_eq!(err.min::<u8>(), Some(1));
+    assert_eq!(err.max::<u8>(), Some(12));
+    assert_eq!(err.clone(), err);
     assert_eq!(
-        TryFromIntError.to_string(),
-        "out of range integral type conversion attempted"
+        format!("{err:?}"),
+        "TryFromIntError { kind: AboveMax, value: 13, min: 1, max: 12 }"
     );
-    assert_eq!(TryFromIntError.clone(), TryFromIntError);
-    assert_eq!(format!("{TryFromIntError:?}"), "TryFromIntError");
-
+    let err = RangedI8::<-5, 5>::try_from(-6).expect_err("value is out of range");
+    assert_eq!(err.to_string(), "-6 is not in the range -5..=5");
+    assert_eq!(err.kind(), RangeErrorKind::BelowMin);
+    assert_eq!(err.value::<u8>(), None);
+
+    let parse_error = |kind, value| ParseIntError {
+        kind,
+        value,
+        min: ErrorInt::Unsigned(1),
+        max: ErrorInt::Unsigned(12),
+    };
     assert_eq!(
-        ParseIntError {
-            kind: IntErrorKind::Empty,
-        }
-        .to_string(),
+        parse_error(IntErrorKind::Empty, None).to_string(),
         "cannot parse integer from empty string"
     );
     assert_eq!(
-        ParseIntError {
-            kind: IntErrorKind::InvalidDigit,
-        }
-        .to_string(),
+        parse_error(IntErrorKind::InvalidDigit, None).to_string(),
         "invalid digit found in string"
     );
     assert_eq!(
-        ParseIntError {
-            kind: IntErrorKind::PosOverflow,
-        }
-        .to_string(),
-        "number too large to fit in target type"
+        parse_error(IntErrorKind::PosOverflow, Some(ErrorInt::Unsigned(13))).to_string(),
+        "13 is not in the range 1..=12"
     );
     assert_eq!(
-        ParseIntError {
-            kind: IntErrorKind::NegOverflow,
-        }
-        .to_string(),
-        "number too small to fit in target type"
+        parse_error(IntErrorKind::PosOverflow, None).to_string(),
+        "number too large to fit in the range 1..=12"
     );
     assert_eq!(
-        ParseIntError {
-            kind: IntErrorKind::Zero,
-        }
-        .to_string(),
-        "number would be zero for non-zero type"
+        parse_error(IntErrorKind::NegOverflow, Some(ErrorInt::Signed(-1))).to_string(),
+        "-1 is not in the range 1..=12"
     );
     assert_eq!(
-        format!(
-            "{:?}",
-            ParseIntError {
-                kind: IntErrorKind::Empty
-            }
-        ),
-        "ParseIntError { kind: Empty }"
+        parse_error(IntErrorKind::NegOverflow, None).to_string(),
+        "number too small to fit in the range 1..=12"
     );
     assert_eq!(
-        ParseIntError {
-            kind: IntErrorKind::Empty
-        }
-        .clone(),
-        ParseIntError {
-            kind: IntErrorKind::Empty
-        }
+        parse_error(IntErrorKind::Zero, None).to_string(),
+        "number would be zero for non-zero type"
     );
     assert_eq!(
-        ParseIntError {
-            kind: IntErrorKind::Empty
-        }
-        .kind(),
-        &IntErrorKind::Empty
+        format!("{:?}", parse_error(IntErrorKind::Empty, None)),
+        "ParseIntError { kind: Empty, value: None, min: 1, max: 12 }"
+    );
+    let err = parse_error(IntErrorKind::Empty, None);
+    assert_eq!(err.clone(), err);
+    assert_eq!(err.kind(), &IntErrorKind::Empty);
+
+    let err = "13"
+        .parse::<RangedU8<1, 12>>()
+        .expect_err("value is out of range");
+    assert_eq!(err.to_string(), "13 is not in the range 1..=12");
+    assert_eq!(err.value::<u8>(), Some(13));
+    assert_eq!(err.min::<u8>(), Some(1));
+    assert_eq!(err.max::<u8>(), Some(12));
+    let err = "300"
+        .parse::<RangedU8<1, 12>>()
+        .expect_err("value is out of range");
+    assert_eq!(err.kind(), &IntErrorKind::PosOverflow);
+    assert_eq!(err.value::<u16>(), Some(300));
+    assert_eq!(err.value::<u8>(), None);
+    assert_eq!(err.to_string(), "300 is not in the range 1..=12");
+    let err = "-300"
+        .parse::<RangedI8<-5, 5>>()
+        .expect_err("value is out of range");
+    assert_eq!(err.kind(), &IntErrorKind::NegOverflow);
+    assert_eq!(err.value::<i16>(), Some(-300));
+    let err = "1000000000000000000000000000000000000000"
+        .parse::<RangedU8<1, 12>>()
+        .expect_err("value is out of range");
+    assert_eq!(err.value::<u128>(), None);
+    assert_eq!(
+        err.to_string(),
+        "number too large to fit in the range 1..=12"
     );
+    let err = "a"
+        .parse::<RangedU8<1, 12>>()
+        .expect_err("value is invalid");
+    assert_eq!(err.kind(), &IntErrorKind::InvalidDigit);
+    assert_eq!(err.value::<u8>(), None);
+}
+
+/// Exercise the `Ranged` trait generically.
+fn ranged_trait_generic<T: Ranged>(one: T::Primitive, two: T::Primitive) {
+    assert!(T::MIN < T::MAX);
+    assert_eq!(T::new(T::MIN.get()), Some(T::MIN));
+    assert_eq!(T::new_saturating(T::MAX.get()), T::MAX);
+    assert_eq!(T::MAX.checked_add(one), None);
+    assert_eq!(T::MIN.checked_sub(one), None);
+    assert_eq!(T::MAX.checked_mul(two), None);
+    assert_eq!(T::MAX.checked_div(one), Some(T::MAX));
+    assert_eq!(T::MAX.checked_rem(T::MAX.get()), None);
+    assert_eq!(T::MAX.checked_neg(), None);
+    assert_eq!(T::MAX.checked_pow(1), Some(T::MAX));
+}
+
+/// Exercise the `OptionalRanged` trait generically.
+fn optional_ranged_trait_generic<T: OptionalRanged>() {
+    let some = T::some(<T::Ranged as Ranged>::MAX);
+    assert!(some.is_some());
+    assert_eq!(some.get(), Some(<T::Ranged as Ranged>::MAX));
+    assert_eq!(some.get_primitive(), Some(<T::Ranged as Ranged>::MAX.get()));
+    assert!(T::NONE.is_none());
+    assert_eq!(T::NONE.get(), None);
+    assert_eq!(T::NONE, T::default());
 }
 
 macro_rules! tests {
@@ -209,18 +886,15 @@ macro_rules! tests {
         fn from_str_radix() {$(
             assert_eq!($t::<5, 10>::from_str_radix("10", 10), Ok($t::<5, 10>::MAX));
             assert_eq!($t::<5, 10>::from_str_radix("5", 10), Ok($t::<5, 10>::MIN));
-            assert_eq!(
-                $t::<5, 10>::from_str_radix("4", 10),
-                Err(ParseIntError { kind: IntErrorKind::NegOverflow }),
-            );
-            assert_eq!(
-                $t::<5, 10>::from_str_radix("11", 10),
-                Err(ParseIntError { kind: IntErrorKind::PosOverflow }),
-            );
-            assert_eq!(
-                $t::<5, 10>::from_str_radix("", 10),
-                Err(ParseIntError { kind: IntErrorKind::Empty }),
-            );
+            let err = $t::<5, 10>::from_str_radix("4", 10).unwrap_err();
+            assert_eq!(err.kind(), &IntErrorKind::NegOverflow);
+            assert_eq!(err.value::<$inner>(), Some(4));
+            let err = $t::<5, 10>::from_str_radix("B", 16).unwrap_err();
+            assert_eq!(err.kind(), &IntErrorKind::PosOverflow);
+            assert_eq!(err.value::<$inner>(), Some(11));
+            let err = $t::<5, 10>::from_str_radix("", 10).unwrap_err();
+            assert_eq!(err.kind(), &IntErrorKind::Empty);
+            assert_eq!(err.value::<$inner>(), None);
         )*}
 
         #[test]
@@ -350,6 +1024,52 @@ macro_rules! tests {
             assert_eq!($t::<-10, 10>::MIN.neg(), $t::<-10, 10>::MAX);
         })*}
 
+        #[test]
+        fn add_ranged() {$(
+            let sum: $t<6, 15> = $t::<5, 10>::MAX.add_ranged($t::<1, 5>::MIN);
+            assert_eq!(sum, $t::<6, 15>::new_static::<11>());
+        )*}
+
+        #[test]
+        fn sub_ranged() {$(
+            let difference: $t<0, 9> = $t::<5, 10>::MAX.sub_ranged($t::<1, 5>::MAX);
+            assert_eq!(difference, $t::<0, 9>::new_static::<5>());
+        )*}
+
+        #[test]
+        fn mul_ranged() {$(
+            let product: $t<5, 50> = $t::<5, 10>::MAX.mul_ranged($t::<1, 5>::MAX);
+            assert_eq!(product, $t::<5, 50>::MAX);
+        )*}
+
+        #[test]
+        fn neg_ranged() {$(if_signed! { $signed
+            let negated: $t<-10, -5> = $t::<5, 10>::MAX.neg_ranged();
+            assert_eq!(negated, $t::<-10, -5>::MIN);
+        })*}
+
+        #[test]
+        fn abs_ranged() {$(if_signed! { $signed
+            let abs: $t<0, 10> = $t::<-10, 5>::MIN.abs_ranged();
+            assert_eq!(abs, $t::<0, 10>::MAX);
+            let abs: $t<5, 10> = $t::<-10, -5>::MAX.abs_ranged();
+            assert_eq!(abs, $t::<5, 10>::MIN);
+        })*}
+
+        #[test]
+        fn rem_ranged() {$(
+            let rem: $t<0, 4> = $t::<5, 10>::MAX.rem_ranged($t::<3, 5>::MIN);
+            assert_eq!(rem, $t::<0, 4>::new_static::<1>());
+            let rem: $t<0, 2> = $t::<0, 2>::MAX.rem_ranged($t::<5, 10>::MIN);
+            assert_eq!(rem, $t::<0, 2>::MAX);
+            if_signed! { $signed
+                let rem: $t<-4, 4> = $t::<-10, 10>::MIN.rem_ranged($t::<-5, -3>::MAX);
+                assert_eq!(rem, $t::<-4, 4>::new_static::<-1>());
+                let rem: $t<0, 0> = $t::<{ $inner::MIN }, 0>::MIN.rem_ranged($t::<-1, -1>::MIN);
+                assert_eq!(rem.get(), 0);
+            }
+        )*}
+
         #[test]
         fn checked_shl() {$(
             assert_eq!($t::<5, 10>::MAX.checked_shl(1), None);
@@ -496,6 +1216,494 @@ macro_rules! tests {
             })*
         }
 
+        #[test]
+        fn wrapping_mul() {
+            $(
+                let wrap = |value: i128| 5 + (value - 5).rem_euclid(6);
+                for value in 5..=10 {
+                    for rhs in 0..=20 {
+                        let expected = wrap(value * rhs);
+                        let ranged = $t::<5, 10>::new($inner::try_from(value).unwrap()).unwrap();
+                        let result = ranged.wrapping_mul($inner::try_from(rhs).unwrap());
+                        assert_eq!(
+                            i128::try_from(result.get()).unwrap(),
+                            expected,
+                            "failed test at {value} * {rhs}"
+                        );
+                    }
+                }
+                assert_eq!(
+                    $t::<0, { $inner::MAX - 1 }>::MAX.wrapping_mul($inner::MAX),
+                    $t::<0, { $inner::MAX - 1 }>::MIN
+                );
+                assert_eq!(
+                    $t::<{ $inner::MIN }, { $inner::MAX }>::MAX.wrapping_mul(3).get(),
+                    $inner::MAX.wrapping_mul(3)
+                );
+            )*
+            $(if_signed! { $signed
+                let wrap = |value: i128| -5 + (value + 5).rem_euclid(13);
+                for value in -5..=7 {
+                    for rhs in -20..=20 {
+                        let expected = wrap(value * rhs);
+                        let ranged = $t::<-5, 7>::new($inner::try_from(value).unwrap()).unwrap();
+                        let result = ranged.wrapping_mul($inner::try_from(rhs).unwrap());
+                        assert_eq!(
+                            i128::try_from(result.get()).unwrap(),
+                            expected,
+                            "failed test at {value} * {rhs}"
+                        );
+                    }
+                }
+                assert_eq!(
+                    $t::<-5, 7>::MIN.wrapping_mul($inner::MIN),
+                    $t::<-5, 7>::MIN.wrapping_mul($inner::MIN.rem_euclid(13))
+                );
+            })*
+        }
+
+        #[test]
+        fn wrapping_neg() {
+            $(
+                assert_eq!($t::<5, 10>::MIN.wrapping_neg(), $t::<5, 10>::new(7).unwrap());
+                assert_eq!($t::<5, 10>::new_static::<6>().wrapping_neg().get(), 6);
+                assert_eq!($t::<5, 10>::new(8).unwrap().wrapping_neg(), $t::<5, 10>::MAX);
+                assert_eq!($t::<0, 10>::MIN.wrapping_neg(), $t::<0, 10>::MIN);
+                assert_eq!($t::<0, 10>::MAX.wrapping_neg(), $t::<0, 10>::new(1).unwrap());
+                assert_eq!(
+                    $t::<{ $inner::MIN }, { $inner::MAX }>::MAX.wrapping_neg(),
+                    $t::<{ $inner::MIN }, { $inner::MAX }>::new($inner::MAX.wrapping_neg()).unwrap()
+                );
+            )*
+            $(if_signed! { $signed
+                for value in -5..=7 {
+                    let ranged = $t::<-5, 7>::new(value).unwrap();
+                    assert_eq!(ranged.wrapping_neg().get(), -5 + (5 - value).rem_euclid(13));
+                }
+                assert_eq!($t::<-5, 5>::MIN.wrapping_neg(), $t::<-5, 5>::MAX);
+                assert_eq!(
+                    $t::<{ $inner::MIN }, { $inner::MAX }>::MIN.wrapping_neg(),
+                    $t::<{ $inner::MIN }, { $inner::MAX }>::MIN
+                );
+                assert_eq!(
+                    $t::<{ $inner::MIN }, 0>::MIN.wrapping_neg(),
+                    $t::<{ $inner::MIN }, 0>::new_static::<-1>()
+                );
+            })*
+        }
+
+        #[test]
+        fn wrapping_pow() {
+            $(
+                let wrap = |value: i128| 5 + (value - 5).rem_euclid(6);
+                for value in 5..=10_i128 {
+                    for exp in 0..=5 {
+                        let expected = wrap(value.pow(exp));
+                        let ranged = $t::<5, 10>::new($inner::try_from(value).unwrap()).unwrap();
+                        assert_eq!(
+                            i128::try_from(ranged.wrapping_pow(exp).get()).unwrap(),
+                            expected,
+                            "failed test at {value} ** {exp}"
+                        );
+                    }
+                }
+                assert_eq!($t::<0, 0>::MIN.wrapping_pow(0), $t::<0, 0>::MIN);
+                assert_eq!($t::<5, 10>::MAX.wrapping_pow(u32::MAX), $t::<5, 10>::MAX);
+                assert_eq!(
+                    $t::<{ $inner::MIN }, { $inner::MAX }>::new_static::<3>().wrapping_pow(9).get(),
+                    $inner::wrapping_pow(3, 9)
+                );
+            )*
+            $(if_signed! { $signed
+                let wrap = |value: i128| -5 + (value + 5).rem_euclid(13);
+                for value in -5..=7_i128 {
+                    for exp in 0..=5 {
+                        let expected = wrap(value.pow(exp));
+                        let ranged = $t::<-5, 7>::new($inner::try_from(value).unwrap()).unwrap();
+                        assert_eq!(
+                            i128::try_from(ranged.wrapping_pow(exp).get()).unwrap(),
+                            expected,
+                            "failed test at {value} ** {exp}"
+                        );
+                    }
+                }
+            })*
+        }
+
+        #[test]
+        fn wrapping_shl() {
+            $(
+                let wrap = |value: i128| 5 + (value - 5).rem_euclid(6);
+                for value in 5..=10 {
+                    for rhs in 0..8 {
+                        let expected = wrap(value << rhs);
+                        let ranged = $t::<5, 10>::new($inner::try_from(value).unwrap()).unwrap();
+                        assert_eq!(
+                            i128::try_from(ranged.wrapping_shl(rhs).get()).unwrap(),
+                            expected,
+                            "failed test at {value} << {rhs}"
+                        );
+                    }
+                    let ranged = $t::<5, 10>::new($inner::try_from(value).unwrap()).unwrap();
+                    assert_eq!(ranged.wrapping_shl($inner::BITS), ranged);
+                }
+                assert_eq!(
+                    $t::<{ $inner::MIN }, { $inner::MAX }>::MAX.wrapping_shl(1).get(),
+                    $inner::MAX.wrapping_shl(1)
+                );
+            )*
+            $(if_signed! { $signed
+                let wrap = |value: i128| -5 + (value + 5).rem_euclid(13);
+                for value in -5..=7 {
+                    for rhs in 0..8 {
+                        let expected = wrap(value << rhs);
+                        let ranged = $t::<-5, 7>::new($inner::try_from(value).unwrap()).unwrap();
+                        assert_eq!(
+                            i128::try_from(ranged.wrapping_shl(rhs).get()).unwrap(),
+                            expected,
+                            "failed test at {value} << {rhs}"
+                        );
+                    }
+                }
+            })*
+        }
+
+        #[test]
+        fn wrapping_shr() {
+            $(
+                assert_eq!($t::<5, 10>::MAX.wrapping_shr(1), $t::<5, 10>::MIN);
+                assert_eq!($t::<5, 10>::MAX.wrapping_shr(2), $t::<5, 10>::new(8).unwrap());
+                assert_eq!($t::<5, 10>::MAX.wrapping_shr($inner::BITS), $t::<5, 10>::MAX);
+                assert_eq!($t::<5, 10>::MAX.wrapping_shr($inner::BITS + 1), $t::<5, 10>::MIN);
+                assert_eq!(
+                    $t::<{ $inner::MIN }, { $inner::MAX }>::MAX.wrapping_shr(3).get(),
+                    $inner::MAX.wrapping_shr(3)
+                );
+            )*
+            $(if_signed! { $signed
+                assert_eq!($t::<-5, 7>::MIN.wrapping_shr(1), $t::<-5, 7>::new(-3).unwrap());
+                assert_eq!($t::<-5, 7>::MIN.wrapping_shr($inner::BITS - 1).get(), -1);
+                assert_eq!($t::<0, 7>::MIN.wrapping_shr(1), $t::<0, 7>::MIN);
+                assert_eq!($t::<0, 7>::MAX.wrapping_shr(1), $t::<0, 7>::new(3).unwrap());
+                assert_eq!($t::<1, 7>::MAX.wrapping_shr(3), $t::<1, 7>::MAX);
+            })*
+        }
+
+        #[test]
+        fn wrapping_abs() {$(if_signed! { $signed
+            assert_eq!($t::<5, 10>::MIN.wrapping_abs(), $t::<5, 10>::MIN);
+            assert_eq!($t::<-5, 3>::MIN.wrapping_abs(), $t::<-5, 3>::new(-4).unwrap());
+            assert_eq!($t::<-5, 3>::new(-2).unwrap().wrapping_abs(), $t::<-5, 3>::new(2).unwrap());
+            assert_eq!($t::<-10, 0>::MIN.wrapping_abs(), $t::<-10, 0>::new(-1).unwrap());
+            assert_eq!($t::<-10, 0>::MAX.wrapping_abs(), $t::<-10, 0>::MAX);
+            assert_eq!(
+                $t::<{ $inner::MIN }, { $inner::MAX }>::MIN.wrapping_abs(),
+                $t::<{ $inner::MIN }, { $inner::MAX }>::MIN
+            );
+        })*}
+
+        #[test]
+        fn overflowing_add() {$(
+            assert_eq!($t::<5, 10>::MIN.overflowing_add(5), ($t::<5, 10>::MAX, false));
+            assert_eq!($t::<5, 10>::MAX.overflowing_add(1), ($t::<5, 10>::MIN, true));
+            assert_eq!(
+                $t::<5, 10>::MAX.overflowing_add($inner::MAX),
+                ($t::<5, 10>::MAX.wrapping_add($inner::MAX), true)
+            );
+            assert_eq!(
+                $t::<{ $inner::MIN }, { $inner::MAX }>::MAX.overflowing_add(1),
+                ($t::<{ $inner::MIN }, { $inner::MAX }>::MIN, true)
+            );
+        )*}
+
+        #[test]
+        fn overflowing_sub() {$(
+            assert_eq!($t::<5, 10>::MAX.overflowing_sub(5), ($t::<5, 10>::MIN, false));
+            assert_eq!($t::<5, 10>::MIN.overflowing_sub(1), ($t::<5, 10>::MAX, true));
+            assert_eq!($t::<5, 10>::MIN.overflowing_sub(6), ($t::<5, 10>::MIN, true));
+        )*}
+
+        #[test]
+        fn overflowing_mul() {$(
+            assert_eq!($t::<5, 10>::MIN.overflowing_mul(2), ($t::<5, 10>::MAX, false));
+            assert_eq!($t::<5, 10>::MAX.overflowing_mul(2), ($t::<5, 10>::new_static::<8>(), true));
+            assert_eq!($t::<0, 10>::MAX.overflowing_mul(0), ($t::<0, 10>::MIN, false));
+        )*}
+
+        #[test]
+        fn overflowing_neg() {
+            $(
+                assert_eq!($t::<0, 10>::MIN.overflowing_neg(), ($t::<0, 10>::MIN, false));
+                assert_eq!(
+                    $t::<0, 10>::MAX.overflowing_neg(),
+                    ($t::<0, 10>::MIN.wrapping_add(1), true)
+                );
+            )*
+            $(if_signed! { $signed
+                assert_eq!($t::<-5, 5>::MIN.overflowing_neg(), ($t::<-5, 5>::MAX, false));
+                assert_eq!(
+                    $t::<-5, 3>::MIN.overflowing_neg(),
+                    ($t::<-5, 3>::MIN.wrapping_add(1), true)
+                );
+            })*
+        }
+
+        #[test]
+        fn overflowing_pow() {$(
+            assert_eq!($t::<5, 10>::MAX.overflowing_pow(1), ($t::<5, 10>::MAX, false));
+            assert_eq!($t::<5, 10>::MAX.overflowing_pow(0), ($t::<5, 10>::new_static::<7>(), true));
+            assert_eq!($t::<5, 10>::MAX.overflowing_pow(2), ($t::<5, 10>::MAX, true));
+        )*}
+
+        #[test]
+        fn is_min_max() {$(
+            assert!($t::<5, 10>::MIN.is_min());
+            assert!(!$t::<5, 10>::MIN.is_max());
+            assert!($t::<5, 10>::MAX.is_max());
+            assert!(!$t::<5, 10>::MAX.is_min());
+            assert!(!$t::<5, 10>::new_static::<7>().is_min());
+            assert!(!$t::<5, 10>::new_static::<7>().is_max());
+            assert!($t::<5, 5>::MIN.is_min() && $t::<5, 5>::MIN.is_max());
+        )*}
+
+        #[test]
+        fn succ() {$(
+            let seven = $t::<5, 10>::new_static::<7>();
+            assert_eq!(seven.checked_succ(), Some($t::<5, 10>::new_static::<8>()));
+            assert_eq!($t::<5, 10>::MAX.checked_succ(), None);
+            assert_eq!(seven.wrapping_succ(), $t::<5, 10>::new_static::<8>());
+            assert_eq!($t::<5, 10>::MAX.wrapping_succ(), $t::<5, 10>::MIN);
+            assert_eq!(seven.saturating_succ(), $t::<5, 10>::new_static::<8>());
+            assert_eq!($t::<5, 10>::MAX.saturating_succ(), $t::<5, 10>::MAX);
+            assert_eq!(
+                $t::<{ $inner::MIN }, { $inner::MAX }>::MAX.wrapping_succ(),
+                $t::<{ $inner::MIN }, { $inner::MAX }>::MIN,
+            );
+        )*}
+
+        #[test]
+        fn pred() {$(
+            let seven = $t::<5, 10>::new_static::<7>();
+            assert_eq!(seven.checked_pred(), Some($t::<5, 10>::new_static::<6>()));
+            assert_eq!($t::<5, 10>::MIN.checked_pred(), None);
+            assert_eq!(seven.wrapping_pred(), $t::<5, 10>::new_static::<6>());
+            assert_eq!($t::<5, 10>::MIN.wrapping_pred(), $t::<5, 10>::MAX);
+            assert_eq!(seven.saturating_pred(), $t::<5, 10>::new_static::<6>());
+            assert_eq!($t::<5, 10>::MIN.saturating_pred(), $t::<5, 10>::MIN);
+            assert_eq!(
+                $t::<{ $inner::MIN }, { $inner::MAX }>::MIN.wrapping_pred(),
+                $t::<{ $inner::MIN }, { $inner::MAX }>::MAX,
+            );
+        )*}
+
+        #[test]
+        fn all_values() {$({
+            const VALUES: AllValues<$t<5, 10>> = $t::<5, 10>::all();
+            assert_eq!(VALUES.map($t::get).collect::<Vec<_>>(), [5, 6, 7, 8, 9, 10]);
+            assert_eq!(VALUES.rev().map($t::get).collect::<Vec<_>>(), [10, 9, 8, 7, 6, 5]);
+            assert_eq!(VALUES.len(), 6);
+            assert_eq!(VALUES.size_hint(), (6, Some(6)));
+            assert_eq!(VALUES.last(), Some($t::<5, 10>::MAX));
+
+            let mut values = VALUES;
+            assert_eq!(values.next(), Some($t::<5, 10>::MIN));
+            assert_eq!(values.next_back(), Some($t::<5, 10>::MAX));
+            assert_eq!(values.nth(1), Some($t::<5, 10>::new_static::<7>()));
+            assert_eq!(values.len(), 2);
+            assert_eq!(values.nth_back(1), Some($t::<5, 10>::new_static::<8>()));
+            assert_eq!(values.len(), 0);
+            assert_eq!(values.next(), None);
+            assert_eq!(values.next_back(), None);
+
+            let mut values = VALUES;
+            assert_eq!(values.nth(6), None);
+            assert_eq!(values.next(), None);
+            let mut values = VALUES;
+            assert_eq!(values.nth_back(usize::MAX), None);
+            assert_eq!(values.next_back(), None);
+            assert_eq!(values.size_hint(), (0, Some(0)));
+
+            let mut values = $t::<5, 5>::all();
+            assert_eq!(values.next_back(), Some($t::<5, 5>::MIN));
+            assert_eq!(values.next(), None);
+
+            let mut values = $t::<{ $inner::MIN }, { $inner::MAX }>::all();
+            assert_eq!(values.next(), Some($t::<{ $inner::MIN }, { $inner::MAX }>::MIN));
+            assert_eq!(values.next_back(), Some($t::<{ $inner::MIN }, { $inner::MAX }>::MAX));
+            assert_eq!(values.nth(1).map($t::get), Some($inner::MIN + 2));
+            assert_eq!(values.nth_back(1).map($t::get), Some($inner::MAX - 2));
+        })*}
+
+        #[test]
+        fn ranged_traits() {$(
+            ranged_trait_generic::<$t<5, 10>>(1, 2);
+            optional_ranged_trait_generic::<$opt<5, 10>>();
+        )*}
+
+        #[test]
+        fn try_ops() {
+            use RangeErrorKind::{AboveMax, BelowMin, DivisionByZero};
+
+            $(
+                let value = $t::<5, 10>::new_static::<8>();
+                assert_eq!(value.try_add(2), Ok($t::<5, 10>::MAX));
+                let err = value.try_add(3).unwrap_err();
+                assert_eq!(err.kind(), AboveMax);
+                assert_eq!(err.value::<$inner>(), Some(11));
+                assert_eq!(err.min::<$inner>(), Some(5));
+                assert_eq!(err.max::<$inner>(), Some(10));
+                assert_eq!(err.to_string(), "11 is not in the range 5..=10");
+                let err = value.try_add($inner::MAX).unwrap_err();
+                assert_eq!(err.kind(), AboveMax);
+                assert_eq!(err.value::<$inner>(), None);
+                assert_eq!(err.to_string(), "result is too large to fit in the range 5..=10");
+
+                assert_eq!(value.try_sub(3), Ok($t::<5, 10>::MIN));
+                let err = value.try_sub(4).unwrap_err();
+                assert_eq!(err.kind(), BelowMin);
+                assert_eq!(err.value::<i128>(), Some(4));
+                assert_eq!(value.try_sub($inner::MAX).unwrap_err().kind(), BelowMin);
+
+                assert_eq!(value.try_mul(1), Ok(value));
+                assert_eq!(value.try_mul(2).unwrap_err().value::<u8>(), Some(16));
+                assert_eq!(value.try_mul($inner::MAX).unwrap_err().kind(), AboveMax);
+
+                assert_eq!($t::<5, 10>::MAX.try_div(2), Ok($t::<5, 10>::MIN));
+                assert_eq!(value.try_div(2).unwrap_err().kind(), BelowMin);
+                let err = value.try_div(0).unwrap_err();
+                assert_eq!(err.kind(), DivisionByZero);
+                assert_eq!(err.value::<$inner>(), None);
+                assert_eq!(err.to_string(), "attempt to divide by zero");
+
+                assert_eq!(value.try_pow(1), Ok(value));
+                assert_eq!(value.try_pow(0).unwrap_err().kind(), BelowMin);
+                assert_eq!(value.try_pow(2).unwrap_err().value::<u8>(), Some(64));
+                assert_eq!(value.try_pow(200).unwrap_err().kind(), AboveMax);
+
+                assert_eq!($t::<0, 10>::MIN.try_neg(), Ok($t::<0, 10>::MIN));
+                assert_eq!(value.try_neg().unwrap_err().kind(), BelowMin);
+            )*
+            $(if_signed! { $signed
+                let value = $t::<-10, 10>::new_static::<-8>();
+                assert_eq!(value.try_add(-3).unwrap_err().value::<$inner>(), Some(-11));
+                assert_eq!(value.try_add($inner::MIN).unwrap_err().kind(), BelowMin);
+                assert_eq!(value.try_sub($inner::MIN).unwrap_err().kind(), AboveMax);
+                assert_eq!(value.try_mul($inner::MAX).unwrap_err().kind(), BelowMin);
+                assert_eq!(value.try_mul($inner::MIN).unwrap_err().kind(), AboveMax);
+                assert_eq!(value.try_pow(199).unwrap_err().kind(), BelowMin);
+                assert_eq!(value.try_pow(200).unwrap_err().kind(), AboveMax);
+                assert_eq!(value.try_neg(), Ok($t::<-10, 10>::new_static::<8>()));
+                assert_eq!(value.try_div(-1), Ok($t::<-10, 10>::new_static::<8>()));
+
+                let min = $t::<{ $inner::MIN }, { $inner::MAX }>::MIN;
+                assert_eq!(min.try_neg().unwrap_err().kind(), AboveMax);
+                assert_eq!(min.try_div(-1).unwrap_err().kind(), AboveMax);
+                let err = $t::<-5, 5>::MIN.try_sub(1).unwrap_err();
+                assert_eq!(err.to_string(), "-6 is not in the range -5..=5");
+                assert_eq!(err.value::<u8>(), None);
+            })*
+        }
+
+        #[test]
+        fn modular_ops() {$(
+            let mut value = Modular($t::<5, 10>::MAX);
+            assert_eq!(value + 1, Modular($t::<5, 10>::MIN));
+            assert_eq!(value - 6, value);
+            assert_eq!(value * 2, Modular($t::<5, 10>::new_static::<8>()));
+            assert_eq!(value + Modular($t::<5, 10>::MIN), Modular($t::<5, 10>::new_static::<9>()));
+            assert_eq!(-value, Modular($t::<5, 10>::new_static::<8>()));
+            value += 1;
+            assert_eq!(value, Modular($t::<5, 10>::MIN));
+            value -= Modular($t::<5, 10>::new_static::<7>());
+            assert_eq!(value, Modular($t::<5, 10>::MAX));
+            value *= 3;
+            assert_eq!(value, Modular($t::<5, 10>::new_static::<6>()));
+            value *= Modular($t::<5, 10>::new_static::<7>());
+            assert_eq!(value, Modular($t::<5, 10>::new_static::<6>()));
+            assert_eq!(format!("{value} {value:?}"), "6 6");
+        )*}
+
+        #[test]
+        fn modular_sum() {$(
+            let values = [
+                Modular($t::<5, 10>::MAX),
+                Modular($t::<5, 10>::MAX),
+                Modular($t::<5, 10>::MIN),
+            ];
+            let expected = Modular($t::<5, 10>::new_static::<7>());
+            assert_eq!(values.iter().sum::<Modular<$t<5, 10>>>(), expected);
+            assert_eq!(values.into_iter().sum::<Modular<$t<5, 10>>>(), expected);
+            assert_eq!(
+                core::iter::empty::<Modular<$t<5, 10>>>().sum::<Modular<_>>(),
+                Modular($t::<5, 10>::new_static::<6>())
+            );
+            assert_eq!(
+                core::iter::empty::<Modular<$t<0, 10>>>().sum::<Modular<_>>(),
+                Modular($t::<0, 10>::MIN)
+            );
+        )*}
+
+        #[test]
+        fn modular_distance() {$(
+            let start = Modular($t::<5, 10>::new_static::<9>());
+            let end = Modular($t::<5, 10>::new_static::<6>());
+            assert_eq!(start.distance_forward(end), 3);
+            assert_eq!(start.distance_backward(end), 3);
+            assert_eq!(end.distance_forward(start), 3);
+            assert_eq!(start.distance_forward(start), 0);
+            assert_eq!(Modular($t::<5, 10>::MIN).distance_forward(Modular($t::<5, 10>::MAX)), 5);
+            assert_eq!(Modular($t::<5, 10>::MIN).distance_backward(Modular($t::<5, 10>::MAX)), 1);
+
+            let min = Modular($t::<{ $inner::MIN }, { $inner::MAX }>::MIN);
+            let max = Modular($t::<{ $inner::MIN }, { $inner::MAX }>::MAX);
+            assert_eq!(max.distance_forward(min), 1);
+            assert_eq!(min.distance_forward(max), max.distance_forward(min).wrapping_neg());
+        )*}
+
+        #[test]
+        fn saturating_wrapper_ops() {
+            $(
+                let mut value = Saturating($t::<5, 10>::new_static::<8>());
+                assert_eq!(value + 5, Saturating($t::<5, 10>::MAX));
+                assert_eq!(value - 5, Saturating($t::<5, 10>::MIN));
+                assert_eq!(value * 2, Saturating($t::<5, 10>::MAX));
+                assert_eq!(value + Saturating($t::<5, 10>::MIN), Saturating($t::<5, 10>::MAX));
+                value -= 1;
+                assert_eq!(value, Saturating($t::<5, 10>::new_static::<7>()));
+                value += Saturating($t::<5, 10>::MIN);
+                assert_eq!(value, Saturating($t::<5, 10>::MAX));
+                value -= Saturating($t::<5, 10>::MAX);
+                assert_eq!(value, Saturating($t::<5, 10>::MIN));
+                value *= 0;
+                assert_eq!(value, Saturating($t::<5, 10>::MIN));
+                value *= Saturating($t::<5, 10>::MIN);
+                assert_eq!(value, Saturating($t::<5, 10>::MAX));
+                assert_eq!(format!("{value} {value:?}"), "10 10");
+            )*
+            $(if_signed! { $signed
+                assert_eq!(-Saturating($t::<-5, 3>::MIN), Saturating($t::<-5, 3>::MAX));
+                assert_eq!((-Saturating($t::<-5, 3>::MAX)).0.get(), -3);
+            })*
+        }
+
+        #[test]
+        fn saturating_wrapper_sum() {$(
+            let values = [
+                Saturating($t::<5, 10>::MAX),
+                Saturating($t::<5, 10>::MIN),
+            ];
+            assert_eq!(values.iter().sum::<Saturating<$t<5, 10>>>(), Saturating($t::<5, 10>::MAX));
+            assert_eq!(values[1..].iter().sum::<Saturating<$t<5, 10>>>(), values[1]);
+            assert_eq!(
+                core::iter::empty::<Saturating<$t<5, 10>>>().sum::<Saturating<_>>(),
+                Saturating($t::<5, 10>::MIN)
+            );
+            assert_eq!(
+                core::iter::empty::<Saturating<$t<0, 10>>>().sum::<Saturating<_>>(),
+                Saturating($t::<0, 10>::MIN)
+            );
+        )*}
+
         #[test]
         fn saturating_sub() {$(
             assert_eq!($t::<5, 10>::MIN.saturating_sub(0), $t::<5, 10>::MIN);
@@ -534,6 +1742,81 @@ macro_rules! tests {
             assert_eq!($t::<5, 10>::MIN.saturating_pow(3), $t::<5, 10>::MAX);
         )*}
 
+        #[test]
+        fn ops() {$(
+            assert_eq!($t::<5, 10>::MIN + 5, $t::<5, 10>::MAX);
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
+            assert_eq!($t::<5, 10>::MIN + $t::<0, 5>::MAX, $t::<5, 10>::MAX);
+            assert_eq!($t::<5, 10>::MAX - 5, $t::<5, 10>::MIN);
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
+            assert_eq!($t::<5, 10>::MAX - $t::<0, 5>::MAX, $t::<5, 10>::MIN);
+            assert_eq!($t::<5, 10>::MIN * 2, $t::<5, 10>::MAX);
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
+            assert_eq!($t::<5, 10>::MIN * $t::<1, 2>::MAX, $t::<5, 10>::MAX);
+            assert_eq!($t::<5, 10>::MAX / 2, $t::<5, 10>::MIN);
+            assert_eq!($t::<5, 10>::MAX / $t::<1, 2>::MAX, $t::<5, 10>::MIN);
+            assert_eq!($t::<5, 10>::MAX % 11, $t::<5, 10>::MAX);
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
+            assert_eq!($t::<5, 10>::MAX % $t::<11, 12>::MIN, $t::<5, 10>::MAX);
+            assert_eq!($t::<5, 10>::MIN << 1, $t::<5, 10>::MAX);
+            assert_eq!($t::<5, 10>::MAX >> 1, $t::<5, 10>::MIN);
+
+            let mut value = $t::<5, 10>::MIN;
+            value += 4;
+            value -= $t::<0, 5>::new_static::<4>();
+            value *= 2;
+            value /= $t::<1, 2>::MAX;
+            value <<= 1;
+            value >>= 1;
+            value += $t::<0, 5>::new_static::<2>();
+            value %= 8;
+            assert_eq!(value, $t::<5, 10>::new_static::<7>());
+
+            assert!(panic::catch_unwind(|| $t::<5, 10>::MAX + 1).is_err());
+            assert!(panic::catch_unwind(|| $t::<5, 10>::MIN - 1).is_err());
+            assert!(panic::catch_unwind(|| $t::<5, 10>::MAX * 2).is_err());
+            assert!(panic::catch_unwind(|| $t::<5, 10>::MAX / 0).is_err());
+            assert!(panic::catch_unwind(|| $t::<5, 10>::MAX % 5).is_err());
+            assert!(panic::catch_unwind(|| $t::<5, 10>::MAX << 1).is_err());
+            assert!(panic::catch_unwind(|| $t::<5, 10>::MIN >> 1).is_err());
+        )*}
+
+        #[test]
+        fn neg_op() {$(if_signed! { $signed
+            assert_eq!(-$t::<-10, 10>::MIN, $t::<-10, 10>::MAX);
+            #[cfg(not(feature = "unstable-generic-const-exprs"))]
+            assert!(panic::catch_unwind(|| -$t::<5, 10>::MIN).is_err());
+        })*}
+
+        #[cfg(feature = "unstable-generic-const-exprs")]
+        #[test]
+        fn inferred_ops() {$(
+            let sum: $t<6, 15> = $t::<5, 10>::MIN + $t::<1, 5>::MAX;
+            assert_eq!(sum.get(), 10);
+            let difference: $t<0, 9> = $t::<5, 10>::MAX - $t::<1, 5>::MAX;
+            assert_eq!(difference.get(), 5);
+            let product: $t<5, 50> = $t::<5, 10>::MAX * $t::<1, 5>::MIN;
+            assert_eq!(product.get(), 10);
+            let remainder: $t<0, 4> = $t::<5, 10>::MAX % $t::<3, 5>::MIN;
+            assert_eq!(remainder.get(), 1);
+            let chained: $t<7, 85> = ($t::<5, 10>::MIN + $t::<1, 5>::MAX) * $t::<1, 5>::MIN
+                + $t::<1, 10>::MIN;
+            assert_eq!(chained.get(), 11);
+
+            if_signed! { $signed
+                let negated: $t<-10, -5> = -$t::<5, 10>::MIN;
+                assert_eq!(negated.get(), -5);
+                let product: $t<-50, 50> = $t::<-5, 10>::MIN * $t::<-5, 5>::MAX;
+                assert_eq!(product.get(), -25);
+                let abs: $t<0, 10> = $t::<-10, 5>::MIN.abs();
+                assert_eq!(abs.get(), 10);
+                let abs: $t<5, 10> = $t::<-10, -5>::MAX.abs();
+                assert_eq!(abs.get(), 5);
+                let remainder: $t<-4, 4> = $t::<-10, 10>::MIN % $t::<-5, -3>::MAX;
+                assert_eq!(remainder.get(), -1);
+            }
+        )*}
+
         #[test]
         fn as_ref() {$(
             assert_eq!($t::<5, 10>::MIN.as_ref(), &5);
@@ -611,17 +1894,28 @@ macro_rules! tests {
         fn try_from() {$(
             assert_eq!($t::<5, 10>::try_from(10), Ok($t::<5, 10>::MAX));
             assert_eq!($t::<5, 10>::try_from(5), Ok($t::<5, 10>::MIN));
-            assert_eq!($t::<5, 10>::try_from(4), Err(TryFromIntError));
-            assert_eq!($t::<5, 10>::try_from(11), Err(TryFromIntError));
+            let err = $t::<5, 10>::try_from(4).unwrap_err();
+            assert_eq!(err.kind(), RangeErrorKind::BelowMin);
+            assert_eq!(err.value::<$inner>(), Some(4));
+            assert_eq!(err.to_string(), "4 is not in the range 5..=10");
+            let err = $t::<5, 10>::try_from(11).unwrap_err();
+            assert_eq!(err.kind(), RangeErrorKind::AboveMax);
+            assert_eq!(err.value::<$inner>(), Some(11));
+            assert_eq!(err.to_string(), "11 is not in the range 5..=10");
         )*}
 
         #[test]
         fn from_str() {$(
             assert_eq!("10".parse::<$t<5, 10>>(), Ok($t::<5, 10>::MAX));
             assert_eq!("5".parse::<$t<5, 10>>(), Ok($t::<5, 10>::MIN));
-            assert_eq!("4".parse::<$t<5, 10>>(), Err(ParseIntError { kind: IntErrorKind::NegOverflow }));
-            assert_eq!("11".parse::<$t<5, 10>>(), Err(ParseIntError { kind: IntErrorKind::PosOverflow }));
-            assert_eq!("".parse::<$t<5, 10>>(), Err(ParseIntError { kind: IntErrorKind::Empty }));
+            let err = "4".parse::<$t<5, 10>>().unwrap_err();
+            assert_eq!(err.kind(), &IntErrorKind::NegOverflow);
+            assert_eq!(err.to_string(), "4 is not in the range 5..=10");
+            let err = "11".parse::<$t<5, 10>>().unwrap_err();
+            assert_eq!(err.kind(), &IntErrorKind::PosOverflow);
+            assert_eq!(err.to_string(), "11 is not in the range 5..=10");
+            let err = "".parse::<$t<5, 10>>().unwrap_err();
+            assert_eq!(err.kind(), &IntErrorKind::Empty);
         )*}
 
         #[cfg(feature = "serde")]
@@ -651,6 +1945,16 @@ macro_rules! tests {
             let val = $opt::<5, 10>::None;
             let serialized = serde_json::to_string(&val)?;
             assert_eq!(serialized, "null");
+            let deserialized: $opt<5, 10> = serde_json::from_str(&serialized)?;
+            assert_eq!(deserialized, val);
+
+            let vals = [$opt::<5, 10>::None, $opt::Some($t::<5, 10>::MIN)];
+            let serialized = serde_json::to_string(&vals)?;
+            assert_eq!(serialized, "[null,5]");
+            let deserialized: [$opt<5, 10>; 2] = serde_json::from_str(&serialized)?;
+            assert_eq!(deserialized, vals);
+            let deserialized: $opt<5, 10> = serde_json::from_value(serde_json::Value::Null)?;
+            assert_eq!(deserialized, $opt::<5, 10>::None);
 
             assert!(serde_json::from_str::<$opt<5, 10>>("").is_err());
             assert!(serde_json::from_str::<$opt<5, 10>>("4").is_err());
@@ -659,6 +1963,28 @@ macro_rules! tests {
             Ok(())
         }
 
+        #[cfg(feature = "unstable-step-trait")]
+        #[test]
+        fn step() {
+            use core::iter::Step;
+
+            $(
+                let start = $t::<5, 10>::new_static::<6>();
+                let forward = (start..=$t::<5, 10>::MAX).map($t::get).collect::<Vec<_>>();
+                assert_eq!(forward, [6, 7, 8, 9, 10]);
+                let backward = (start..$t::<5, 10>::MAX).rev().map($t::get).collect::<Vec<_>>();
+                assert_eq!(backward, [9, 8, 7, 6]);
+                assert_eq!(($t::<5, 10>::MIN..=$t::<5, 10>::MAX).step_by(2).count(), 3);
+                assert_eq!(Step::steps_between(&start, &$t::<5, 10>::MAX), (4, Some(4)));
+                assert_eq!(Step::steps_between(&$t::<5, 10>::MAX, &start), (0, None));
+                assert_eq!(Step::forward_checked(start, 4), Some($t::<5, 10>::MAX));
+                assert_eq!(Step::forward_checked(start, 5), None);
+                assert_eq!(Step::forward_checked(start, usize::MAX), None);
+                assert_eq!(Step::backward_checked(start, 1), Some($t::<5, 10>::MIN));
+                assert_eq!(Step::backward_checked(start, 2), None);
+            )*
+        }
+
         #[cfg(feature = "rand08")]
         #[test]
         fn rand08() {$(
@@ -0,0 +1,132 @@
+//! Declaration of traits that abstract over all ranged integer types.
+
+use core::fmt;
+use core::hash::Hash;
+
+/// Prevents downstream crates from implementing the traits in this module.
+mod sealed {
+    /// A type that is a ranged integer or optional ranged integer provided by this crate.
+    #[expect(unnameable_types, reason = "sealed trait")]
+    pub trait Sealed {}
+
+    /// A ranged integer whose values can be mapped to consecutive indices starting at zero.
+    #[expect(unnameable_types, reason = "sealed trait")]
+    pub trait Dense: Sealed + Copy {
+        /// The number of values in the range, or `None` if it does not fit in a `usize`.
+        const LEN: Option<usize>;
+
+        /// Returns the distance of the value from `MIN`.
+        ///
+        /// The result is only meaningful when `LEN` is `Some`.
+        fn to_index(self) -> usize;
+
+        /// Returns the value at the given distance from `MIN`.
+        ///
+        /// # Safety
+        ///
+        /// `LEN` must be `Some` and `index` must be less than it.
+        unsafe fn from_index_unchecked(index: usize) -> Self;
+    }
+}
+
+pub(crate) use sealed::{Dense, Sealed};
+
+/// A ranged integer of any width.
+///
+/// This trait is implemented for every ranged integer type, such as [`RangedU8`](crate::RangedU8)
+/// and [`RangedI64`](crate::RangedI64), permitting code that is generic over them. It is sealed
+/// and cannot be implemented outside of this crate.
+///
+/// The methods of this trait forward to the inherent methods of the same name.
+///
+/// # Example
+///
+/// ```rust
+/// # use deranged::{Ranged, RangedI32, RangedU8};
+/// fn describe<T: Ranged>(value: T) -> String {
+///     format!("{value} (allowed: {}..={})", T::MIN, T::MAX)
+/// }
+///
+/// assert_eq!(
+///     describe(RangedU8::<1, 12>::new_static::<3>()),
+///     "3 (allowed: 1..=12)"
+/// );
+/// assert_eq!(describe(RangedI32::<-5, 5>::MIN), "-5 (allowed: -5..=5)");
+/// ```
+pub trait Ranged: Sealed + Dense + Copy + Ord + Hash + fmt::Debug + fmt::Display {
+    /// The primitive integer type used to store the value.
+    type Primitive: Copy + Ord + Hash + fmt::Debug + fmt::Display;
+
+    /// The smallest value that can be represented by this type.
+    const MIN: Self;
+
+    /// The largest value that can be represented by this type.
+    const MAX: Self;
+
+    /// Returns the value as a primitive type.
+    fn get(self) -> Self::Primitive;
+
+    /// Creates a ranged integer if the given value is in the range `MIN..=MAX`.
+    fn new(value: Self::Primitive) -> Option<Self>;
+
+    /// Creates a ranged integer with the given value, saturating if it is out of range.
+    fn new_saturating(value: Self::Primitive) -> Self;
+
+    /// Checked integer addition. Computes `self + rhs`, returning `None` if the resulting value
+    /// is out of range.
+    fn checked_add(self, rhs: Self::Primitive) -> Option<Self>;
+
+    /// Checked integer subtraction. Computes `self - rhs`, returning `None` if the resulting
+    /// value is out of range.
+    fn checked_sub(self, rhs: Self::Primitive) -> Option<Self>;
+
+    /// Checked integer multiplication. Computes `self * rhs`, returning `None` if the resulting
+    /// value is out of range.
+    fn checked_mul(self, rhs: Self::Primitive) -> Option<Self>;
+
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or if
+    /// the resulting value is out of range.
+    fn checked_div(self, rhs: Self::Primitive) -> Option<Self>;
+
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0` or if
+    /// the resulting value is out of range.
+    fn checked_rem(self, rhs: Self::Primitive) -> Option<Self>;
+
+    /// Checked negation. Computes `-self`, returning `None` if the resulting value is out of
+    /// range.
+    fn checked_neg(self) -> Option<Self>;
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if the resulting value
+    /// is out of range.
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+}
+
+/// An optional ranged integer of any width.
+///
+/// This trait is implemented for every optional ranged integer type, such as
+/// [`OptionRangedU8`](crate::OptionRangedU8), permitting code that is generic over them. It is
+/// sealed and cannot be implemented outside of this crate.
+///
+/// The methods of this trait forward to the inherent methods of the same name.
+pub trait OptionalRanged: Sealed + Copy + Eq + Hash + Default + fmt::Debug {
+    /// The ranged integer type that is optionally present.
+    type Ranged: Ranged;
+
+    /// An optional ranged value that is not present.
+    const NONE: Self;
+
+    /// Creates an optional ranged value that is present.
+    fn some(value: Self::Ranged) -> Self;
+
+    /// Returns the value as the standard library's [`Option`] type.
+    fn get(self) -> Option<Self::Ranged>;
+
+    /// Obtain the value as an `Option` of the primitive type.
+    fn get_primitive(self) -> Option<<Self::Ranged as Ranged>::Primitive>;
+
+    /// Returns `true` if the value is not present.
+    fn is_none(&self) -> bool;
+
+    /// Returns `true` if the value is present.
+    fn is_some(&self) -> bool;
+}